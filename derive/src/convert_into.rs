@@ -0,0 +1,126 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Expr, Type};
+
+/// A conversion into `to`, which is fallible if an `alt` type is given.
+struct Conversion
+{
+    to: Type,
+    alt: Option<Type>,
+    with: Option<Expr>
+}
+
+fn parse_conversions(input: &DeriveInput) -> syn::Result<Vec<Conversion>>
+{
+    let mut conversions = vec![];
+    for attr in input.attrs.iter()
+        .filter(|attr| attr.path().is_ident("convert"))
+    {
+        let mut into = None;
+        let mut try_into = None;
+        let mut alt = None;
+        let mut with = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("into")
+            {
+                into = Some(meta.value()?.parse::<Type>()?);
+            }
+            else if meta.path.is_ident("try_into")
+            {
+                try_into = Some(meta.value()?.parse::<Type>()?);
+            }
+            else if meta.path.is_ident("alt")
+            {
+                alt = Some(meta.value()?.parse::<Type>()?);
+            }
+            else if meta.path.is_ident("with")
+            {
+                with = Some(meta.value()?.parse::<Expr>()?);
+            }
+            else
+            {
+                return Err(meta.error("expected `into`, `try_into`, `alt` or `with`"))
+            }
+            Ok(())
+        })?;
+
+        conversions.push(match (into, try_into, alt)
+        {
+            (Some(to), None, None) => Conversion {to, alt: None, with},
+            (None, Some(to), Some(alt)) => Conversion {to, alt: Some(alt), with},
+            (None, Some(_), None) => return Err(syn::Error::new_spanned(attr, "`try_into` needs an `alt` type to give back on failure")),
+            (Some(_), None, Some(_)) => return Err(syn::Error::new_spanned(attr, "`alt` can only be used with `try_into`")),
+            (Some(_), Some(_), _) => return Err(syn::Error::new_spanned(attr, "expected either `into` or `try_into`, not both")),
+            (None, None, _) => return Err(syn::Error::new_spanned(attr, "expected `into` or `try_into`"))
+        });
+    }
+    if conversions.is_empty()
+    {
+        return Err(syn::Error::new_spanned(&input.ident, "expected at least one `#[convert(...)]` attribute"))
+    }
+    Ok(conversions)
+}
+
+pub fn convert_into(input: TokenStream) -> syn::Result<TokenStream>
+{
+    let input: DeriveInput = syn::parse2(input)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let impls = parse_conversions(&input)?
+        .into_iter()
+        .map(|Conversion {to, alt, with}| match alt
+        {
+            None => {
+                let convert = match with
+                {
+                    Some(with) => quote!((#with)(*self)),
+                    None => quote!(<#to as ::core::convert::From<Self>>::from(*self))
+                };
+                quote!(
+                    impl #impl_generics ::spellcast::convert::ConvertInto<#to> for #ident #ty_generics
+                    #where_clause
+                    {
+                        fn convert_into(self: ::spellcast::alloc::boxed::Box<Self>) -> ::spellcast::alloc::boxed::Box<#to>
+                        {
+                            ::spellcast::alloc::boxed::Box::new(#convert)
+                        }
+                    }
+                )
+            },
+            Some(alt) => {
+                // On failure, the original value must be given back, so the error of the conversion has to be `Self`.
+                let mut generics = input.generics.clone();
+                let convert = match with
+                {
+                    Some(with) => quote!((#with)(*self)),
+                    None => {
+                        generics.make_where_clause()
+                            .predicates
+                            .push(parse_quote!(#to: ::core::convert::TryFrom<#ident #ty_generics, Error = #ident #ty_generics>));
+                        quote!(<#to as ::core::convert::TryFrom<Self>>::try_from(*self))
+                    }
+                };
+                let (_, _, where_clause) = generics.split_for_impl();
+                quote!(
+                    impl #impl_generics ::spellcast::convert::TryConvertInto<#to, #alt> for #ident #ty_generics
+                    #where_clause
+                    {
+                        fn try_convert_into(self: ::spellcast::alloc::boxed::Box<Self>) -> ::core::result::Result<::spellcast::alloc::boxed::Box<#to>, ::spellcast::alloc::boxed::Box<#alt>>
+                        {
+                            match #convert
+                            {
+                                ::core::result::Result::Ok(to) => ::core::result::Result::Ok(::spellcast::alloc::boxed::Box::new(to)),
+                                ::core::result::Result::Err(from) => {
+                                    let from: Self = from;
+                                    ::core::result::Result::Err(::spellcast::Is::<#alt>::into_obj(::spellcast::alloc::boxed::Box::new(from)))
+                                }
+                            }
+                        }
+                    }
+                )
+            }
+        });
+
+    Ok(quote!(#(#impls)*))
+}
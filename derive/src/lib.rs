@@ -0,0 +1,60 @@
+//! Procedural macros for [spellcast](https://crates.io/crates/spellcast).
+//! 
+//! These are re-exported by spellcast under the `derive` feature, and are not meant to be used directly.
+
+use proc_macro::TokenStream;
+
+mod convert_into;
+mod object;
+
+/// Declares a trait as an object trait.
+/// 
+/// This adds [Any](core::any::Any) as a supertrait, and implements the same casts from the trait object into itself as `spellcast::impl_object!`.
+/// 
+/// Lifetimes, type parameters, const generics and associated types are supported. The trait object is implemented for any choice of its associated types.
+/// 
+/// Standard traits can be implemented for the trait object by listing them, like `#[object(PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]`,
+/// where `Clone` is implemented for the boxed trait object.
+/// This also adds the matching helper trait, `spellcast::DynEq`, `spellcast::DynTotalEq`, `spellcast::DynHash`, `spellcast::DynOrd` or `spellcast::DynClone`, as a supertrait.
+/// 
+/// # Examples
+/// ```ignore
+/// #[spellcast::object]
+/// trait PlayerObj<'a, T, const N: usize>
+/// {
+///     type Item;
+/// 
+///     fn items(&self) -> [&'a T; N];
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn object(attr: TokenStream, item: TokenStream) -> TokenStream
+{
+    object::object(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `spellcast::convert::ConvertInto` and `spellcast::convert::TryConvertInto` from `#[convert(...)]` attributes.
+/// 
+/// - `#[convert(into = To)]` converts using `From<Self>` for `To`.
+/// - `#[convert(into = To, with = f)]` converts using `f: fn(Self) -> To`.
+/// - `#[convert(try_into = To, alt = Alt)]` converts using `TryFrom<Self, Error = Self>` for `To`.
+/// - `#[convert(try_into = To, alt = Alt, with = f)]` converts using `f: fn(Self) -> Result<To, Self>`.
+/// 
+/// Fallible conversions give the original value back on failure, boxed as the `alt` type, so `Self` must be an object of it.
+/// 
+/// # Examples
+/// ```ignore
+/// #[derive(ConvertInto)]
+/// #[convert(into = Human, with = bot_to_human)]
+/// #[convert(try_into = Npc, alt = dyn PlayerObj)]
+/// struct Bot;
+/// ```
+#[proc_macro_derive(ConvertInto, attributes(convert))]
+pub fn convert_into(input: TokenStream) -> TokenStream
+{
+    convert_into::convert_into(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,254 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse::Parser, parse_quote, punctuated::Punctuated, GenericParam, Ident, ItemTrait, Token, TraitItem, Type, WherePredicate};
+
+pub fn object(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
+{
+    let with = Punctuated::<Ident, Token![,]>::parse_terminated.parse2(attr)?;
+
+    let mut item: ItemTrait = syn::parse2(item)?;
+    item.supertraits.push(parse_quote!(::core::any::Any));
+
+    // Standard traits for the trait object are implemented through the matching helper trait, which is added as a supertrait.
+    let mut helpers: Vec<Ident> = Vec::new();
+    for ident in with.iter()
+    {
+        let helper = match ident.to_string().as_str()
+        {
+            "PartialEq" => format_ident!("DynEq"),
+            "Eq" => format_ident!("DynTotalEq"),
+            "Hash" => format_ident!("DynHash"),
+            "PartialOrd" | "Ord" => format_ident!("DynOrd"),
+            "Clone" => format_ident!("DynClone"),
+            _ => return Err(syn::Error::new_spanned(ident, "expected one of `PartialEq`, `Eq`, `Hash`, `PartialOrd`, `Ord` or `Clone`"))
+        };
+        if !helpers.contains(&helper)
+        {
+            item.supertraits.push(parse_quote!(::spellcast::#helper));
+            helpers.push(helper);
+        }
+    }
+
+    let ident = &item.ident;
+    let mut generics = item.generics.clone();
+    let mut args: Vec<TokenStream> = generics.params.iter()
+        .map(|param| match param
+        {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote!(#lifetime)
+            },
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            },
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+        }).collect();
+
+    // Trait objects must name all their associated types, so the impls are made generic over them.
+    for trait_item in item.items.iter()
+    {
+        if let TraitItem::Type(assoc) = trait_item
+        {
+            if !assoc.generics.params.is_empty()
+            {
+                return Err(syn::Error::new_spanned(&assoc.generics, "generic associated types are not supported in trait objects"))
+            }
+            let assoc_ident = &assoc.ident;
+            let param = format_ident!("__{}", assoc_ident);
+            let bounds = &assoc.bounds;
+            generics.params.push(parse_quote!(#param: #bounds));
+            args.push(quote!(#assoc_ident = #param));
+        }
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let obj: Type = parse_quote!(dyn #ident<#(#args),*> + 'static);
+
+    // Without the `nightly` feature, trait objects can't be unsized generically, so `Is` and `AsAny` are implemented here.
+    let stable = if cfg!(feature = "nightly")
+    {
+        quote!()
+    }
+    else
+    {
+        // `Any` is only implemented for the trait object when all its generics are `'static`.
+        let mut any_generics = generics.clone();
+        let bounds: Vec<WherePredicate> = generics.lifetimes()
+            .map(|param| {
+                let lifetime = &param.lifetime;
+                parse_quote!(#lifetime: 'static)
+            }).chain(generics.type_params()
+                .map(|param| {
+                    let ident = &param.ident;
+                    parse_quote!(#ident: 'static)
+                })
+            ).collect();
+        any_generics.make_where_clause()
+            .predicates
+            .extend(bounds);
+        let (any_impl_generics, _, any_where_clause) = any_generics.split_for_impl();
+
+        // The `Send` and `Send + Sync` variants of the trait object are covered too, so that they keep their auto traits when given back.
+        let variants = [quote!(), quote!(+ ::core::marker::Send), quote!(+ ::core::marker::Send + ::core::marker::Sync)]
+            .into_iter()
+            .map(|auto| {
+                let obj: Type = parse_quote!(dyn #ident<#(#args),*> #auto + 'static);
+                quote!(
+                    unsafe impl #impl_generics ::spellcast::Is<#obj> for #obj
+                    #where_clause
+                    {
+                        fn as_obj_ptr(from: *const Self) -> *const (#obj)
+                        {
+                            from
+                        }
+                        fn as_obj_ptr_mut(from: *mut Self) -> *mut (#obj)
+                        {
+                            from
+                        }
+                    }
+                    impl #any_impl_generics ::spellcast::AsAny for #obj
+                    #any_where_clause
+                    {
+                        fn as_any<'__a>(self: &'__a Self) -> &'__a dyn ::core::any::Any
+                        {
+                            self
+                        }
+                        fn as_any_mut<'__a>(self: &'__a mut Self) -> &'__a mut dyn ::core::any::Any
+                        {
+                            self
+                        }
+                        fn into_any(self: ::spellcast::alloc::boxed::Box<Self>) -> ::spellcast::alloc::boxed::Box<dyn ::core::any::Any>
+                        {
+                            self
+                        }
+                    }
+                )
+            });
+        quote!(#(#variants)*)
+    };
+
+    let with = with.iter()
+        .map(|with| match with.to_string().as_str()
+        {
+            "PartialEq" => quote!(
+                impl #impl_generics ::core::cmp::PartialEq for #obj
+                #where_clause
+                {
+                    fn eq(&self, other: &Self) -> bool
+                    {
+                        ::spellcast::DynEq::dyn_eq(self, ::spellcast::AsAny::as_any(other))
+                    }
+                }
+            ),
+            "Eq" => {
+                // Only objects of structs implementing `Eq` are totally equal, which `DynTotalEq` guarantees.
+                let mut eq_generics = generics.clone();
+                eq_generics.make_where_clause()
+                    .predicates
+                    .push(parse_quote!(#obj: ::spellcast::DynTotalEq));
+                let (_, _, eq_where_clause) = eq_generics.split_for_impl();
+                quote!(
+                    impl #impl_generics ::core::cmp::Eq for #obj
+                    #eq_where_clause {}
+                )
+            },
+            "Hash" => quote!(
+                impl #impl_generics ::core::hash::Hash for #obj
+                #where_clause
+                {
+                    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H)
+                    {
+                        ::spellcast::DynHash::dyn_hash(self, state)
+                    }
+                }
+            ),
+            "PartialOrd" => quote!(
+                impl #impl_generics ::core::cmp::PartialOrd for #obj
+                #where_clause
+                {
+                    fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering>
+                    {
+                        ::core::option::Option::Some(::spellcast::DynOrd::dyn_cmp(self, ::spellcast::AsAny::as_any(other)))
+                    }
+                }
+            ),
+            "Clone" => {
+                let impls = [quote!(), quote!(+ ::core::marker::Send), quote!(+ ::core::marker::Send + ::core::marker::Sync)]
+                    .into_iter()
+                    .map(|auto| quote!(
+                        impl #impl_generics ::core::clone::Clone for ::spellcast::alloc::boxed::Box<dyn #ident<#(#args),*> #auto + 'static>
+                        #where_clause
+                        {
+                            fn clone(&self) -> Self
+                            {
+                                ::spellcast::clone_box(&**self)
+                            }
+                        }
+                    ));
+                quote!(#(#impls)*)
+            },
+            _ => quote!(
+                impl #impl_generics ::core::cmp::Ord for #obj
+                #where_clause
+                {
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering
+                    {
+                        ::spellcast::DynOrd::dyn_cmp(self, ::spellcast::AsAny::as_any(other))
+                    }
+                }
+            )
+        });
+
+    Ok(quote!(
+        #item
+
+        #stable
+
+        #(#with)*
+
+        impl #impl_generics ::spellcast::downcast::DowncastFromRef<#obj> for #obj
+        #where_clause
+        {
+            fn downcast_from_ref<'__a>(from: &'__a (#obj)) -> ::core::option::Option<&'__a Self>
+            {
+                ::core::option::Option::Some(from)
+            }
+            fn downcast_from_mut<'__a>(from: &'__a mut (#obj)) -> ::core::option::Option<&'__a mut Self>
+            {
+                ::core::option::Option::Some(from)
+            }
+        }
+        impl #impl_generics ::spellcast::downcast::DowncastFrom<#obj, #obj> for #obj
+        #where_clause
+        {
+            fn downcast_from(from: ::spellcast::alloc::boxed::Box<#obj>) -> ::core::result::Result<::spellcast::alloc::boxed::Box<Self>, ::spellcast::alloc::boxed::Box<#obj>>
+            {
+                ::core::result::Result::Ok(from)
+            }
+            fn downcast_from_pin(from: ::core::pin::Pin<::spellcast::alloc::boxed::Box<#obj>>) -> ::core::result::Result<::core::pin::Pin<::spellcast::alloc::boxed::Box<Self>>, ::core::pin::Pin<::spellcast::alloc::boxed::Box<#obj>>>
+            {
+                ::core::result::Result::Ok(from)
+            }
+            fn downcast_from_rc(from: ::spellcast::alloc::rc::Rc<#obj>) -> ::core::result::Result<::spellcast::alloc::rc::Rc<Self>, ::spellcast::alloc::rc::Rc<#obj>>
+            {
+                ::core::result::Result::Ok(from)
+            }
+            fn downcast_from_arc(from: ::spellcast::alloc::sync::Arc<#obj>) -> ::core::result::Result<::spellcast::alloc::sync::Arc<Self>, ::spellcast::alloc::sync::Arc<#obj>>
+            {
+                ::core::result::Result::Ok(from)
+            }
+        }
+        impl #impl_generics ::spellcast::convert::TryConvertInto<#obj, #obj> for #obj
+        #where_clause
+        {
+            fn try_convert_into(self: ::spellcast::alloc::boxed::Box<Self>) -> ::core::result::Result<::spellcast::alloc::boxed::Box<#obj>, ::spellcast::alloc::boxed::Box<#obj>>
+            {
+                ::core::result::Result::Ok(self)
+            }
+        }
+    ))
+}
@@ -1,12 +1,114 @@
-use std::{any::Any, marker::Unsize};
+use core::any::Any;
 
-pub trait AsAny: Any + Unsize<dyn Any>
+use alloc::boxed::Box;
+
+use super::*;
+
+/// A trait for viewing an object as [Any](Any), preserving its concrete type.
+/// 
+/// With the `nightly` feature, this is implemented for every type that unsizes into `dyn Any`.
+/// Without it, it is implemented for all sized types, and trait-objects must implement it with [impl_object](crate::impl_object).
+pub trait AsAny: Any + private::MaybeUnsize<dyn Any>
+{
+    fn as_any(self: &Self) -> &dyn Any;
+    fn as_any_mut(self: &mut Self) -> &mut dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+
+    /// Views a thread-safe object as [Any](Any), keeping it thread-safe.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{AsAny, impl_object, impl_is};
+    /// use core::any::Any;
+    /// 
+    /// trait JobObj: Any {}
+    /// impl_object!(JobObj);
+    /// 
+    /// struct Render;
+    /// 
+    /// impl JobObj for Render {}
+    /// 
+    /// impl_is!(Render: dyn JobObj);
+    /// 
+    /// let job = Box::new(Render) as Box<dyn JobObj + Send + Sync>;
+    /// 
+    /// let any: &(dyn Any + Send + Sync) = (*job).as_any_send_sync();
+    /// assert!(any.is::<Render>());
+    /// ```
+    fn as_any_send_sync<'a>(self: &'a Self) -> &'a (dyn Any + Send + Sync)
+    where
+        Self: Send + Sync
+    {
+        // SAFETY: The object is Send and Sync, and Any has no methods depending on auto traits, so its vtable is unchanged.
+        unsafe {core::mem::transmute::<&'a dyn Any, &'a (dyn Any + Send + Sync)>(self.as_any())}
+    }
+    /// Converts a sendable object into [Any](Any), keeping it sendable.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{AsAny, impl_object, impl_is};
+    /// use core::any::Any;
+    /// 
+    /// trait JobObj: Any {}
+    /// impl_object!(JobObj);
+    /// 
+    /// struct Render;
+    /// 
+    /// impl JobObj for Render {}
+    /// 
+    /// impl_is!(Render: dyn JobObj);
+    /// 
+    /// let job = Box::new(Render) as Box<dyn JobObj + Send>;
+    /// 
+    /// let any: Box<dyn Any + Send> = job.into_any_send();
+    /// std::thread::spawn(move || assert!(any.is::<Render>())).join().unwrap();
+    /// ```
+    fn into_any_send(self: Box<Self>) -> Box<dyn Any + Send>
+    where
+        Self: Send
+    {
+        // SAFETY: The object is Send, and Any has no methods depending on auto traits, so its vtable is unchanged.
+        unsafe {core::mem::transmute::<Box<dyn Any>, Box<dyn Any + Send>>(self.into_any())}
+    }
+    /// Converts a thread-safe object into [Any](Any), keeping it thread-safe.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{AsAny, impl_object, impl_is};
+    /// use core::any::Any;
+    /// 
+    /// trait JobObj: Any {}
+    /// impl_object!(JobObj);
+    /// 
+    /// struct Render;
+    /// 
+    /// impl JobObj for Render {}
+    /// 
+    /// impl_is!(Render: dyn JobObj);
+    /// 
+    /// let job = Box::new(Render) as Box<dyn JobObj + Send + Sync>;
+    /// 
+    /// let any: Box<dyn Any + Send + Sync> = job.into_any_send_sync();
+    /// assert!(any.downcast::<Render>().is_ok());
+    /// ```
+    fn into_any_send_sync(self: Box<Self>) -> Box<dyn Any + Send + Sync>
+    where
+        Self: Send + Sync
+    {
+        // SAFETY: The object is Send and Sync, and Any has no methods depending on auto traits, so its vtable is unchanged.
+        unsafe {core::mem::transmute::<Box<dyn Any>, Box<dyn Any + Send + Sync>>(self.into_any())}
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T> AsAny for T
+where T: Any + core::marker::Unsize<dyn Any> + ?Sized
 {
-    fn as_any<'a>(self: &'a Self) -> &'a dyn Any
+    fn as_any(self: &Self) -> &dyn Any
     {
         self
     }
-    fn as_any_mut<'a>(self: &'a mut Self) -> &'a mut dyn Any
+    fn as_any_mut(self: &mut Self) -> &mut dyn Any
     {
         self
     }
@@ -16,5 +118,20 @@ pub trait AsAny: Any + Unsize<dyn Any>
     }
 }
 
+#[cfg(not(feature = "nightly"))]
 impl<T> AsAny for T
-where T: Any + Unsize<dyn Any> + ?Sized {}
\ No newline at end of file
+where T: Any
+{
+    fn as_any(self: &Self) -> &dyn Any
+    {
+        self
+    }
+    fn as_any_mut(self: &mut Self) -> &mut dyn Any
+    {
+        self
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn Any>
+    {
+        self
+    }
+}
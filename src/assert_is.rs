@@ -0,0 +1,16 @@
+pub macro assert_is {
+    ($type:ty : $trait:path) => {
+        static_assertions::assert_obj_safe!(crate::Object<dyn $trait>);
+        static_assertions::assert_impl_one!(dyn core::any::Any: core::marker::Unsize<dyn core::any::Any>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::Is<dyn $trait>);
+        static_assertions::assert_impl_one!($type: core::marker::Unsize<dyn $trait>);
+        static_assertions::assert_impl_one!($type: crate::Is<dyn $trait>);
+        static_assertions::assert_impl_one!($type: crate::upcast::Upcast<dyn $trait>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::DowncastFrom<dyn $trait, dyn $trait>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::DowncastFromRef<dyn $trait>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::DowncastRef<dyn $trait>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::Downcast<dyn $trait, dyn $trait>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::Downcast<$type, dyn $trait>);
+        static_assertions::assert_impl_one!(dyn $trait: crate::Object<dyn $trait>);
+    }
+}
\ No newline at end of file
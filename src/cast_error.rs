@@ -0,0 +1,117 @@
+use core::{any::{self, TypeId}, error::Error, fmt::{self, Debug, Display}};
+
+/// An error returned by failed casts, carrying the original value.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{downcast::*, CastError, impl_object, impl_is};
+/// use core::any::{Any, TypeId};
+/// 
+/// // Object trait must extend Any to support downcasts.
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// 
+/// struct Human;
+/// struct Bot;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// 
+/// let error: CastError<Box<dyn PlayerObj>> = Human::downcast_from_checked(player).err().unwrap();
+/// 
+/// assert_eq!(error.source_type_id(), TypeId::of::<Bot>());
+/// assert!(error.source_type_name().ends_with("PlayerObj"));
+/// assert!(error.target_type_name().ends_with("Human"));
+/// 
+/// // The original object is given back.
+/// let player: Box<dyn PlayerObj> = error.into_inner();
+/// ```
+pub struct CastError<T>
+{
+    value: T,
+    source_type_name: &'static str,
+    source_type_id: TypeId,
+    target_type_name: &'static str
+}
+
+impl<T> CastError<T>
+{
+    /// Creates an error for a failed cast from `From` into `To`, where `type_id` is the [TypeId](TypeId) of the concrete type of the value.
+    pub fn new<From, To>(value: T, type_id: TypeId) -> Self
+    where
+        From: ?Sized,
+        To: ?Sized
+    {
+        Self {
+            value,
+            source_type_name: any::type_name::<From>(),
+            source_type_id: type_id,
+            target_type_name: any::type_name::<To>()
+        }
+    }
+
+    /// The name of the type that was cast from.
+    /// 
+    /// For trait objects, this is the name of the trait object, not of its concrete type.
+    pub fn source_type_name(&self) -> &'static str
+    {
+        self.source_type_name
+    }
+
+    /// The [TypeId](TypeId) of the concrete type of the value that was cast from.
+    pub fn source_type_id(&self) -> TypeId
+    {
+        self.source_type_id
+    }
+
+    /// The name of the type that was requested.
+    pub fn target_type_name(&self) -> &'static str
+    {
+        self.target_type_name
+    }
+
+    /// Returns a reference to the original value.
+    pub fn get_ref(&self) -> &T
+    {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the original value.
+    pub fn get_mut(&mut self) -> &mut T
+    {
+        &mut self.value
+    }
+
+    /// Gives back the original value.
+    pub fn into_inner(self) -> T
+    {
+        self.value
+    }
+}
+
+impl<T> Display for CastError<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "cannot cast `{}` ({:?}) into `{}`", self.source_type_name, self.source_type_id, self.target_type_name)
+    }
+}
+
+impl<T> Debug for CastError<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("CastError")
+            .field("source_type_name", &self.source_type_name)
+            .field("source_type_id", &self.source_type_id)
+            .field("target_type_name", &self.target_type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Error for CastError<T> {}
@@ -1,4 +1,4 @@
-use std::{any::{self, Any}, marker::Unsize};
+use alloc::boxed::Box;
 
 use crate::upcast::Upcast;
 
@@ -8,25 +8,22 @@ use super::*;
 
 /// Type conversion trait. Relies on implementation of [ConvertInto](ConvertInto)
 /// 
+/// Objects are converted in place, without being cloned. If the conversion panics, the process is aborted,
+/// since the object has been moved out of the box it is replacing.
+/// 
 /// # Examples
 /// ```rust
-/// #![feature(unsize)]
-/// 
-/// use spellcast::{convert::*, impl_object};
-/// use clone_box::clone_box;
+/// use spellcast::{convert::*, impl_object, impl_is};
 /// use core::any::Any;
 /// 
-/// #[clone_box]
 /// trait PlayerObj: Any + ConvertInto<Human>
 /// {
 ///     fn is_human(&self) -> bool;
 /// }
 /// impl_object!(PlayerObj);
 /// 
-/// #[derive(Clone)]
 /// struct Human;
 /// 
-/// #[clone_box]
 /// impl PlayerObj for Human
 /// {
 ///     fn is_human(&self) -> bool
@@ -42,10 +39,8 @@ use super::*;
 ///     }
 /// }
 /// 
-/// #[derive(Clone)]
 /// struct Bot;
 /// 
-/// #[clone_box]
 /// impl PlayerObj for Bot
 /// {
 ///     fn is_human(&self) -> bool
@@ -61,6 +56,9 @@ use super::*;
 ///     }
 /// }
 /// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// 
 /// let mut player = Box::new(Bot) as Box<dyn PlayerObj>;
 /// 
 /// assert!(!player.is_human());
@@ -81,9 +79,8 @@ where
 }
 impl<To, Obj> Convert<Obj> for To
 where
-    To: Is<Obj> + Unsize<Obj> + 'static,
-    Obj: ConvertInto<Self> + AsAny + ?Sized + 'static,
-    Box<Obj>: Clone
+    To: Is<Obj> + 'static,
+    Obj: ConvertInto<Self> + AsAny + ?Sized + 'static
 {
     fn convert_from(object: Box<Obj>) -> Box<Self>
     {
@@ -93,17 +90,17 @@ where
     {
         if Self::downcast_from_ref(&**object).is_none()
         {
-            *object = object.clone().convert_into()
+            replace_with(object, |object| (Self::convert_from(object).into_obj(), ()))
         }
     }
     fn convert_get(object: &mut Box<Obj>) -> &Self
     {
         Self::convert(object);
-        (&**object).downcast_ref().unwrap()
+        (**object).downcast_ref().unwrap()
     }
     fn convert_get_mut(object: &mut Box<Obj>) -> &mut Self
     {
         Self::convert(object);
-        (&mut **object).downcast_mut().unwrap()
+        (**object).downcast_mut().unwrap()
     }
 }
\ No newline at end of file
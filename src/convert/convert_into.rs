@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 pub trait ConvertInto<To>
 where
     To: ?Sized
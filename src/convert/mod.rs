@@ -7,4 +7,36 @@ moddef::moddef!(
     }
 );
 
-use super::*;
\ No newline at end of file
+use super::*;
+
+#[cfg(feature = "derive")]
+pub use spellcast_derive::ConvertInto;
+/// Moves the value out of `dest`, replaces it with the result of `f`, and returns the rest of the result.
+/// 
+/// There is no valid value in `dest` while `f` runs, so if `f` panics, the process is aborted rather than unwinding past a moved-out value.
+fn replace_with<T, R>(dest: &mut T, f: impl FnOnce(T) -> (T, R)) -> R
+{
+    struct AbortOnUnwind;
+
+    impl Drop for AbortOnUnwind
+    {
+        fn drop(&mut self)
+        {
+            #[cfg(feature = "std")]
+            std::process::abort();
+            // Panicking while already panicking aborts.
+            #[cfg(not(feature = "std"))]
+            panic!("conversion panicked while the object was moved out");
+        }
+    }
+
+    let guard = AbortOnUnwind;
+    // SAFETY: The value is read out and always written back before returning, and the process aborts if `f` unwinds in between.
+    let result = unsafe {
+        let (value, result) = f(core::ptr::read(dest));
+        core::ptr::write(dest, value);
+        result
+    };
+    core::mem::forget(guard);
+    result
+}
@@ -1,9 +1,13 @@
-use std::marker::Unsize;
+use alloc::boxed::Box;
 
-use crate::downcast::{DowncastRef, DowncastFromRef};
+use crate::{downcast::{DowncastRef, DowncastFromRef}, AsAny, CastError};
 
 use super::*;
 
+/// Fallible type conversion trait. Relies on implementation of [TryConvertInto](TryConvertInto)
+/// 
+/// Objects are converted in place, without being cloned. If the conversion panics, the process is aborted,
+/// since the object has been moved out of the box it is replacing.
 pub trait TryConvert<Obj>: Is<Obj>
 where
     Obj: ?Sized
@@ -12,12 +16,20 @@ where
     fn try_convert(object: &mut Box<Obj>) -> bool;
     fn try_convert_get(object: &mut Box<Obj>) -> Option<&Self>;
     fn try_convert_get_mut(object: &mut Box<Obj>) -> Option<&mut Self>;
+    fn try_convert_from_checked(object: Box<Obj>) -> Result<Box<Self>, CastError<Box<Obj>>>
+    where
+        Obj: AsAny;
+    fn try_convert_get_checked(object: &mut Box<Obj>) -> Result<&Self, CastError<&Obj>>
+    where
+        Obj: AsAny;
+    fn try_convert_get_mut_checked(object: &mut Box<Obj>) -> Result<&mut Self, CastError<&mut Obj>>
+    where
+        Obj: AsAny;
 }
 impl<'a, To, Obj> TryConvert<Obj> for To
 where
-    To: Is<Obj> + Unsize<Obj> + ?Sized + DowncastFromRef<Obj>,
-    Obj: Is<Obj> + TryConvertInto<To, Obj> + DowncastRef<To> + ?Sized,
-    Box<Obj>: Clone
+    To: Is<Obj> + ?Sized + DowncastFromRef<Obj>,
+    Obj: Is<Obj> + TryConvertInto<To, Obj> + DowncastRef<To> + ?Sized
 {
     fn try_convert_from(object: Box<Obj>) -> Result<Box<Self>, Box<Obj>>
     {
@@ -27,16 +39,11 @@ where
     {
         if Self::downcast_from_ref(&**object).is_none()
         {
-            match object.clone().try_convert_into()
+            return replace_with(object, |object| match Self::try_convert_from(object)
             {
-                Ok(obj) => {
-                    *object = obj;
-                },
-                Err(obj) => {
-                    *object = obj;
-                    return false;
-                },
-            }
+                Ok(obj) => (obj.into_obj(), true),
+                Err(obj) => (obj, false)
+            })
         }
         true
     }
@@ -46,7 +53,7 @@ where
         {
             return None
         }
-        (&**object).downcast_ref()
+        (**object).downcast_ref()
     }
     fn try_convert_get_mut(object: &mut Box<Obj>) -> Option<&mut Self>
     {
@@ -54,6 +61,38 @@ where
         {
             return None
         }
-        (&mut **object).downcast_mut()
+        (**object).downcast_mut()
+    }
+    fn try_convert_from_checked(object: Box<Obj>) -> Result<Box<Self>, CastError<Box<Obj>>>
+    where
+        Obj: AsAny
+    {
+        object.try_convert_into()
+            .map_err(|object| {
+                let type_id = (*object).as_any().type_id();
+                CastError::new::<Obj, Self>(object, type_id)
+            })
+    }
+    fn try_convert_get_checked(object: &mut Box<Obj>) -> Result<&Self, CastError<&Obj>>
+    where
+        Obj: AsAny
+    {
+        if !Self::try_convert(object)
+        {
+            let type_id = (**object).as_any().type_id();
+            return Err(CastError::new::<Obj, Self>(&**object, type_id))
+        }
+        Ok((**object).downcast_ref().unwrap())
+    }
+    fn try_convert_get_mut_checked(object: &mut Box<Obj>) -> Result<&mut Self, CastError<&mut Obj>>
+    where
+        Obj: AsAny
+    {
+        if !Self::try_convert(object)
+        {
+            let type_id = (**object).as_any().type_id();
+            return Err(CastError::new::<Obj, Self>(&mut **object, type_id))
+        }
+        Ok((**object).downcast_mut().unwrap())
     }
 }
\ No newline at end of file
@@ -1,5 +1,63 @@
+use alloc::boxed::Box;
+
 use super::*;
 
+/// Fallible conversion of a boxed object into another type, giving the object back as `Alt` on failure.
+/// 
+/// This is implemented for every type implementing [ConvertInto](ConvertInto), which never fails.
+/// Types without an infallible conversion can implement it directly, or derive it with `#[convert(try_into = To, alt = Alt)]`.
+/// 
+/// There is no fallback that fails for every other pair of types, and the impl from [ConvertInto](ConvertInto) can't be overridden.
+/// Either would need to specialize on [ConvertInto](ConvertInto), whose impls may depend on lifetimes, which isn't sound,
+/// and would make every crate implementing this trait enable `#![feature(specialization)]`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{convert::*, impl_is};
+/// 
+/// trait PlayerObj {}
+/// 
+/// struct Human;
+/// struct Bot;
+/// struct Npc
+/// {
+///     is_alive: bool
+/// }
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl PlayerObj for Npc {}
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// impl_is!(Npc: dyn PlayerObj);
+/// 
+/// impl ConvertInto<Human> for Bot
+/// {
+///     fn convert_into(self: Box<Self>) -> Box<Human>
+///     {
+///         Box::new(Human)
+///     }
+/// }
+/// 
+/// impl TryConvertInto<Human, dyn PlayerObj> for Npc
+/// {
+///     fn try_convert_into(self: Box<Self>) -> Result<Box<Human>, Box<dyn PlayerObj>>
+///     {
+///         if !self.is_alive
+///         {
+///             return Err(self)
+///         }
+///         Ok(Box::new(Human))
+///     }
+/// }
+/// 
+/// let human: Result<Box<Human>, Box<dyn PlayerObj>> = Box::new(Bot).try_convert_into();
+/// assert!(human.is_ok());
+/// 
+/// let human: Result<Box<Human>, Box<dyn PlayerObj>> = Box::new(Npc {is_alive: false}).try_convert_into();
+/// assert!(human.is_err());
+/// ```
 pub trait TryConvertInto<To, Alt>: Is<Alt>
 where
     To: ?Sized,
@@ -7,7 +65,7 @@ where
 {
     fn try_convert_into(self: Box<Self>) -> Result<Box<To>, Box<Alt>>;
 }
-/*default impl<From, To, Alt> TryConvertInto<To, Alt> for From
+impl<From, To, Alt> TryConvertInto<To, Alt> for From
 where
     From: ConvertInto<To> + Is<Alt> + ?Sized,
     To: ?Sized,
@@ -17,4 +75,4 @@ where
     {
         Ok(self.convert_into())
     }
-}*/
\ No newline at end of file
+}
\ No newline at end of file
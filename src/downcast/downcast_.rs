@@ -1,22 +1,31 @@
-use crate::Is;
+use core::pin::Pin;
+
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
+use crate::{AsAny, CastError, Is};
 
 use super::*;
 
 /// A trait for downcasting objects.
 /// 
+/// To downcast into the first match among several candidate structs, see [DowncastOneOf](DowncastOneOf).
+/// 
 /// # Examples
 /// ```rust
-/// use spellcast::{downcast::*};
+/// use spellcast::{downcast::*, impl_object, impl_is};
 /// use core::{fmt::Debug, any::Any};
 /// 
 /// // Object trait must extend Any to support downcasts.
 /// trait PlayerObj: Any + Debug {}
+/// impl_object!(PlayerObj);
 /// 
 /// #[derive(PartialEq, Debug)]
 /// struct Human;
 /// 
 /// impl PlayerObj for Human {}
 /// 
+/// impl_is!(Human: dyn PlayerObj);
+/// 
 /// let player = Box::new(Human) as Box<dyn PlayerObj>;
 /// 
 /// let human: Result<Box<Human>, Box<dyn PlayerObj>> = player.downcast();
@@ -31,23 +40,166 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::{downcast::*};
+    /// use spellcast::{downcast::*, impl_object, impl_is};
     /// use core::{fmt::Debug, any::Any};
     /// 
     /// // Object trait must extend Any to support downcasts.
     /// trait PlayerObj: Any + Debug {}
+    /// impl_object!(PlayerObj);
     /// 
     /// #[derive(PartialEq, Debug)]
     /// struct Human;
     /// 
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
     /// let player = Box::new(Human) as Box<dyn PlayerObj>;
     /// 
     /// let human: Result<Box<Human>, Box<dyn PlayerObj>> = player.downcast();
     /// assert_eq!(*human.unwrap(), Human);
     /// ```
     fn downcast(self: Box<Self>) -> Result<Box<To>, Box<Obj>>;
+
+    /// Downcasts an object into a struct implementing the object's trait.
+    /// 
+    /// On failure, a [CastError](CastError) describing the failed cast is returned, which gives back the original object.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, CastError, impl_object, impl_is};
+    /// use core::any::{Any, TypeId};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+    /// 
+    /// let human: Result<Box<Human>, CastError<Box<dyn PlayerObj>>> = player.downcast_checked();
+    /// let error = human.err().unwrap();
+    /// assert_eq!(error.source_type_id(), TypeId::of::<Bot>());
+    /// ```
+    fn downcast_checked(self: Box<Self>) -> Result<Box<To>, CastError<Box<Obj>>>
+    where
+        Self: AsAny;
+
+    /// Downcasts a pinned object into a pinned struct implementing the object's trait.
+    /// 
+    /// The object is never moved or unpinned in the process, and on failure, the original pinned object is given back.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::{fmt::Debug, any::Any, marker::PhantomPinned, pin::Pin};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait TaskObj: Any + Debug {}
+    /// impl_object!(TaskObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Sleep
+    /// {
+    ///     _pin: PhantomPinned
+    /// }
+    /// #[derive(PartialEq, Debug)]
+    /// struct Yield;
+    /// 
+    /// impl TaskObj for Sleep {}
+    /// impl TaskObj for Yield {}
+    /// 
+    /// impl_is!(Sleep: dyn TaskObj);
+    /// impl_is!(Yield: dyn TaskObj);
+    /// 
+    /// let task = Box::pin(Sleep {_pin: PhantomPinned}) as Pin<Box<dyn TaskObj>>;
+    /// 
+    /// let task_yield: Result<Pin<Box<Yield>>, Pin<Box<dyn TaskObj>>> = task.downcast_pin();
+    /// let task = task_yield.unwrap_err();
+    /// 
+    /// let sleep: Result<Pin<Box<Sleep>>, Pin<Box<dyn TaskObj>>> = task.downcast_pin();
+    /// assert!(sleep.is_ok());
+    /// ```
+    fn downcast_pin(self: Pin<Box<Self>>) -> Result<Pin<Box<To>>, Pin<Box<Obj>>>;
+
+    /// Downcasts a reference-counted object into a struct implementing the object's trait.
+    /// 
+    /// On failure, the original object is given back.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::{fmt::Debug, any::Any};
+    /// use std::rc::Rc;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any + Debug {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// #[derive(PartialEq, Debug)]
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let player = Rc::new(Bot) as Rc<dyn PlayerObj>;
+    /// 
+    /// let human: Result<Rc<Human>, Rc<dyn PlayerObj>> = player.downcast_rc();
+    /// let player = human.unwrap_err();
+    /// 
+    /// let bot: Result<Rc<Bot>, Rc<dyn PlayerObj>> = player.downcast_rc();
+    /// assert_eq!(*bot.unwrap(), Bot);
+    /// ```
+    fn downcast_rc(self: Rc<Self>) -> Result<Rc<To>, Rc<Obj>>;
+
+    /// Downcasts an atomically reference-counted object into a struct implementing the object's trait.
+    /// 
+    /// On failure, the original object is given back.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::{fmt::Debug, any::Any};
+    /// use std::sync::Arc;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any + Debug {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// #[derive(PartialEq, Debug)]
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj + Send + Sync);
+    /// impl_is!(Bot: dyn PlayerObj + Send + Sync);
+    /// 
+    /// let player = Arc::new(Bot) as Arc<dyn PlayerObj + Send + Sync>;
+    /// 
+    /// let human: Result<Arc<Human>, Arc<dyn PlayerObj + Send + Sync>> = player.downcast_arc();
+    /// let player = human.unwrap_err();
+    /// 
+    /// let bot: Result<Arc<Bot>, Arc<dyn PlayerObj + Send + Sync>> = player.downcast_arc();
+    /// assert_eq!(*bot.unwrap(), Bot);
+    /// ```
+    fn downcast_arc(self: Arc<Self>) -> Result<Arc<To>, Arc<Obj>>;
 }
 impl<'a, From, To, Obj> Downcast<To, Obj> for From
 where
@@ -59,4 +211,22 @@ where
     {
         To::downcast_from(self)
     }
+    fn downcast_checked(self: Box<Self>) -> Result<Box<To>, CastError<Box<Obj>>>
+    where
+        Self: AsAny
+    {
+        To::downcast_from_checked(self)
+    }
+    fn downcast_pin(self: Pin<Box<Self>>) -> Result<Pin<Box<To>>, Pin<Box<Obj>>>
+    {
+        To::downcast_from_pin(self)
+    }
+    fn downcast_rc(self: Rc<Self>) -> Result<Rc<To>, Rc<Obj>>
+    {
+        To::downcast_from_rc(self)
+    }
+    fn downcast_arc(self: Arc<Self>) -> Result<Arc<To>, Arc<Obj>>
+    {
+        To::downcast_from_arc(self)
+    }
 }
\ No newline at end of file
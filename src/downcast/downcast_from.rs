@@ -1,4 +1,8 @@
-use crate::{Is, AsAny};
+use core::pin::Pin;
+
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
+use crate::{Is, AsAny, CastError};
 
 use super::*;
 
@@ -6,17 +10,20 @@ use super::*;
 /// 
 /// # Examples
 /// ```rust
-/// use spellcast::{downcast::*};
+/// use spellcast::{downcast::*, impl_object, impl_is};
 /// use core::{fmt::Debug, any::Any};
 /// 
 /// // Object trait must extend Any to support downcasts.
 /// trait PlayerObj: Any + Debug {}
+/// impl_object!(PlayerObj);
 /// 
 /// #[derive(PartialEq, Debug)]
 /// struct Human;
 /// 
 /// impl PlayerObj for Human {}
 /// 
+/// impl_is!(Human: dyn PlayerObj);
+/// 
 /// let player = Box::new(Human) as Box<dyn PlayerObj>;
 /// 
 /// let human: Result<Box<Human>, Box<dyn PlayerObj>> = Human::downcast_from(player);
@@ -31,23 +38,146 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::{downcast::*};
+    /// use spellcast::{downcast::*, impl_object, impl_is};
     /// use core::{fmt::Debug, any::Any};
     /// 
     /// // Object trait must extend Any to support downcasts.
     /// trait PlayerObj: Any + Debug {}
+    /// impl_object!(PlayerObj);
     /// 
     /// #[derive(PartialEq, Debug)]
     /// struct Human;
     /// 
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
     /// let player = Box::new(Human) as Box<dyn PlayerObj>;
     /// 
     /// let human: Result<Box<Human>, Box<dyn PlayerObj>> = Human::downcast_from(player);
     /// assert_eq!(*human.unwrap(), Human);
     /// ```
     fn downcast_from(from: Box<From>) -> Result<Box<Self>, Box<Obj>>;
+
+    /// Downcasts an object into a struct implementing the object's trait.
+    /// 
+    /// On failure, a [CastError](CastError) describing the failed cast is returned, which gives back the original object.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::{Any, TypeId};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+    /// 
+    /// let error = Human::downcast_from_checked(player).err().unwrap();
+    /// assert_eq!(error.source_type_id(), TypeId::of::<Bot>());
+    /// 
+    /// let player: Box<dyn PlayerObj> = error.into_inner();
+    /// ```
+    fn downcast_from_checked(from: Box<From>) -> Result<Box<Self>, CastError<Box<Obj>>>
+    where
+        From: AsAny
+    {
+        let type_id = (*from).as_any().type_id();
+        Self::downcast_from(from).map_err(|from| CastError::new::<From, Self>(from, type_id))
+    }
+
+    /// Downcasts a pinned object into a pinned struct implementing the object's trait.
+    /// 
+    /// The object is never moved or unpinned in the process, and on failure, the original pinned object is given back.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::{fmt::Debug, any::Any, marker::PhantomPinned, pin::Pin};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait TaskObj: Any + Debug {}
+    /// impl_object!(TaskObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Sleep
+    /// {
+    ///     _pin: PhantomPinned
+    /// }
+    /// 
+    /// impl TaskObj for Sleep {}
+    /// 
+    /// impl_is!(Sleep: dyn TaskObj);
+    /// 
+    /// let task = Box::pin(Sleep {_pin: PhantomPinned}) as Pin<Box<dyn TaskObj>>;
+    /// 
+    /// let sleep: Result<Pin<Box<Sleep>>, Pin<Box<dyn TaskObj>>> = Sleep::downcast_from_pin(task);
+    /// assert!(sleep.is_ok());
+    /// ```
+    fn downcast_from_pin(from: Pin<Box<From>>) -> Result<Pin<Box<Self>>, Pin<Box<Obj>>>;
+
+    /// Downcasts a reference-counted object into a struct implementing the object's trait.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::{fmt::Debug, any::Any};
+    /// use std::rc::Rc;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any + Debug {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
+    /// let player = Rc::new(Human) as Rc<dyn PlayerObj>;
+    /// 
+    /// let human: Result<Rc<Human>, Rc<dyn PlayerObj>> = Human::downcast_from_rc(player);
+    /// assert_eq!(*human.unwrap(), Human);
+    /// ```
+    fn downcast_from_rc(from: Rc<From>) -> Result<Rc<Self>, Rc<Obj>>;
+
+    /// Downcasts an atomically reference-counted object into a struct implementing the object's trait.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::{fmt::Debug, any::Any};
+    /// use std::sync::Arc;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any + Debug {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj + Send + Sync);
+    /// 
+    /// let player = Arc::new(Human) as Arc<dyn PlayerObj + Send + Sync>;
+    /// 
+    /// let human: Result<Arc<Human>, Arc<dyn PlayerObj + Send + Sync>> = Human::downcast_from_arc(player);
+    /// assert_eq!(*human.unwrap(), Human);
+    /// ```
+    fn downcast_from_arc(from: Arc<From>) -> Result<Arc<Self>, Arc<Obj>>;
 }
 impl<From, To, Obj> DowncastFrom<From, Obj> for To
 where
@@ -57,10 +187,38 @@ where
 {
     fn downcast_from(from: Box<From>) -> Result<Box<Self>, Box<Obj>>
     {
-        if !from.as_any().is::<To>()
+        if (*from).as_any().is::<To>()
         {
             return Ok(from.into_any().downcast().unwrap());
         }
-        return Err(from)
+        Err(from.into_obj())
+    }
+    fn downcast_from_pin(from: Pin<Box<From>>) -> Result<Pin<Box<Self>>, Pin<Box<Obj>>>
+    {
+        if (*from).as_any().is::<To>()
+        {
+            // SAFETY: The box is only re-typed, and is immediately pinned again. The object itself is never moved.
+            let from = unsafe {Pin::into_inner_unchecked(from)};
+            return Ok(Box::into_pin(from.into_any().downcast().unwrap()));
+        }
+        Err(from.into_obj_pin())
+    }
+    fn downcast_from_rc(from: Rc<From>) -> Result<Rc<Self>, Rc<Obj>>
+    {
+        if (*from).as_any().is::<To>()
+        {
+            // SAFETY: The object has been checked to be of the concrete type `To`, so the data pointer points to a `To`.
+            return Ok(unsafe {Rc::from_raw(Rc::into_raw(from) as *const To)});
+        }
+        Err(from.into_obj_rc())
+    }
+    fn downcast_from_arc(from: Arc<From>) -> Result<Arc<Self>, Arc<Obj>>
+    {
+        if (*from).as_any().is::<To>()
+        {
+            // SAFETY: The object has been checked to be of the concrete type `To`, so the data pointer points to a `To`.
+            return Ok(unsafe {Arc::from_raw(Arc::into_raw(from) as *const To)});
+        }
+        Err(from.into_obj_arc())
     }
 }
\ No newline at end of file
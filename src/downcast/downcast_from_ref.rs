@@ -1,20 +1,25 @@
+use crate::{AsAny, CastError};
+
 use super::*;
 
 /// A trait for downcasting objects.
 /// 
 /// # Examples
 /// ```rust
-/// use spellcast::{downcast::*};
+/// use spellcast::{downcast::*, impl_object, impl_is};
 /// use core::any::Any;
 /// 
 /// // Object trait must extend Any to support downcasts.
 /// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
 /// 
 /// #[derive(PartialEq, Debug)]
 /// struct Human;
 /// 
 /// impl PlayerObj for Human {}
 /// 
+/// impl_is!(Human: dyn PlayerObj);
+/// 
 /// let player = Box::new(Human) as Box<dyn PlayerObj>;
 /// 
 /// assert_eq!(Human::downcast_from_ref(&*player), Some(&Human));
@@ -27,28 +32,31 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::{downcast::*};
+    /// use spellcast::{downcast::*, impl_object, impl_is};
     /// use core::any::Any;
     /// 
     /// // Object trait must extend Any to support downcasts.
     /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
     /// 
     /// #[derive(PartialEq, Debug)]
     /// struct Human;
     /// 
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
     /// let player = Box::new(Human) as Box<dyn PlayerObj>;
     /// 
     /// assert_eq!(Human::downcast_from_ref(&*player), Some(&Human));
     /// ```
-    fn downcast_from_ref<'a>(from: &'a From) -> Option<&'a Self>;
+    fn downcast_from_ref(from: &From) -> Option<&Self>;
     
     /// Downcasts an object into a struct implementing the object's trait.
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::{downcast::*};
+    /// use spellcast::{downcast::*, impl_object, impl_is};
     /// use core::any::Any;
     /// 
     /// // Object trait must extend Any to support downcasts.
@@ -56,6 +64,7 @@ where
     /// {
     ///     fn is_alive(&self) -> bool;
     /// }
+    /// impl_object!(PlayerObj);
     /// 
     /// struct Human
     /// {
@@ -70,6 +79,8 @@ where
     ///     }
     /// }
     /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
     /// let mut player = Box::new(Human {is_alive: true}) as Box<dyn PlayerObj>;
     /// 
     /// assert_eq!(player.is_alive(), true);
@@ -82,18 +93,93 @@ where
     /// 
     /// assert_eq!(player.is_alive(), false);
     /// ```
-    fn downcast_from_mut<'a>(from: &'a mut From) -> Option<&'a mut Self>;
+    fn downcast_from_mut(from: &mut From) -> Option<&mut Self>;
+
+    /// Downcasts an object into a struct implementing the object's trait.
+    /// 
+    /// On failure, a [CastError](CastError) describing the failed cast is returned.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::{Any, TypeId};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+    /// 
+    /// let error = Human::downcast_from_ref_checked(&*player).unwrap_err();
+    /// assert_eq!(error.source_type_id(), TypeId::of::<Bot>());
+    /// ```
+    fn downcast_from_ref_checked(from: &From) -> Result<&Self, CastError<&From>>
+    where
+        From: AsAny
+    {
+        Self::downcast_from_ref(from).ok_or_else(|| CastError::new::<From, Self>(from, from.as_any().type_id()))
+    }
+
+    /// Downcasts an object into a struct implementing the object's trait.
+    /// 
+    /// On failure, a [CastError](CastError) describing the failed cast is returned.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::{Any, TypeId};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let mut player = Box::new(Bot) as Box<dyn PlayerObj>;
+    /// 
+    /// let error = Human::downcast_from_mut_checked(&mut *player).unwrap_err();
+    /// assert_eq!(error.source_type_id(), TypeId::of::<Bot>());
+    /// ```
+    fn downcast_from_mut_checked(from: &mut From) -> Result<&mut Self, CastError<&mut From>>
+    where
+        From: AsAny
+    {
+        let type_id = (*from).as_any().type_id();
+        if Self::downcast_from_ref(from).is_none()
+        {
+            return Err(CastError::new::<From, Self>(from, type_id))
+        }
+        Ok(Self::downcast_from_mut(from).unwrap())
+    }
 }
 impl<From, To> DowncastFromRef<From> for To
 where
     From: AsAny + ?Sized,
     To: Is<From> + 'static
 {
-    fn downcast_from_ref<'a>(from: &'a From) -> Option<&'a Self>
+    fn downcast_from_ref(from: &From) -> Option<&Self>
     {
         from.as_any().downcast_ref()
     }
-    fn downcast_from_mut<'a>(from: &'a mut From) -> Option<&'a mut Self>
+    fn downcast_from_mut(from: &mut From) -> Option<&mut Self>
     {
         from.as_any_mut().downcast_mut()
     }
@@ -0,0 +1,188 @@
+use alloc::boxed::Box;
+
+use super::*;
+
+/// A trait for downcasting objects into one of several candidate structs.
+/// 
+/// This is the tuple counterpart of [DowncastRef](DowncastRef) and [Downcast](Downcast), and is exported alongside them, so `use spellcast::downcast::*` brings all of them into scope.
+/// It is kept as a separate trait since those are generic over the target struct, which could not be inferred from a tuple of candidates.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{downcast::*, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// // Object trait must extend Any to support downcasts.
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Human;
+/// #[derive(PartialEq, Debug)]
+/// struct Bot;
+/// #[derive(PartialEq, Debug)]
+/// struct Npc;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl PlayerObj for Npc {}
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// impl_is!(Npc: dyn PlayerObj);
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// 
+/// match (*player).downcast_one_of_ref::<(Human, Bot)>()
+/// {
+///     Some(OneOf2::A(human)) => assert_eq!(human, &Human),
+///     Some(OneOf2::B(bot)) => assert_eq!(bot, &Bot),
+///     None => unreachable!()
+/// }
+/// ```
+pub trait DowncastOneOf
+{
+    /// Downcasts an object into the first of the candidate structs that matches.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::Any;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// #[derive(PartialEq, Debug)]
+    /// struct Bot;
+    /// #[derive(PartialEq, Debug)]
+    /// struct Npc;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// impl PlayerObj for Npc {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// impl_is!(Npc: dyn PlayerObj);
+    /// 
+    /// let player = Box::new(Npc) as Box<dyn PlayerObj>;
+    /// 
+    /// assert_eq!((*player).downcast_one_of_ref::<(Human, Bot, Npc)>(), Some(OneOf3::C(&Npc)));
+    /// assert_eq!((*player).downcast_one_of_ref::<(Human, Bot)>(), None);
+    /// ```
+    fn downcast_one_of_ref<Tuple>(self: &Self) -> Option<Tuple::Ref<'_>>
+    where
+        Tuple: Candidates<Self>
+    {
+        Tuple::downcast_one_of_ref(self)
+    }
+
+    /// Downcasts an object into the first of the candidate structs that matches.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::Any;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any
+    /// {
+    ///     fn is_alive(&self) -> bool;
+    /// }
+    /// impl_object!(PlayerObj);
+    /// 
+    /// struct Human
+    /// {
+    ///     is_alive: bool
+    /// }
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human
+    /// {
+    ///     fn is_alive(&self) -> bool
+    ///     {
+    ///         self.is_alive
+    ///     }
+    /// }
+    /// impl PlayerObj for Bot
+    /// {
+    ///     fn is_alive(&self) -> bool
+    ///     {
+    ///         false
+    ///     }
+    /// }
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let mut player = Box::new(Human {is_alive: true}) as Box<dyn PlayerObj>;
+    /// 
+    /// if let Some(OneOf2::B(human)) = (*player).downcast_one_of_mut::<(Bot, Human)>()
+    /// {
+    ///     human.is_alive = false;
+    /// }
+    /// 
+    /// assert_eq!(player.is_alive(), false);
+    /// ```
+    fn downcast_one_of_mut<Tuple>(self: &mut Self) -> Option<Tuple::Mut<'_>>
+    where
+        Tuple: Candidates<Self>
+    {
+        Tuple::downcast_one_of_mut(self)
+    }
+
+    /// Downcasts an object into the first of the candidate structs that matches.
+    /// 
+    /// On failure, the original object is given back.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::Any;
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// #[derive(PartialEq, Debug)]
+    /// struct Bot;
+    /// #[derive(PartialEq, Debug)]
+    /// struct Npc;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// impl PlayerObj for Npc {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// impl_is!(Npc: dyn PlayerObj);
+    /// 
+    /// let player = Box::new(Npc) as Box<dyn PlayerObj>;
+    /// 
+    /// let player = match player.downcast_one_of::<(Human, Bot)>()
+    /// {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(player) => player
+    /// };
+    /// 
+    /// match player.downcast_one_of::<(Human, Bot, Npc)>()
+    /// {
+    ///     Ok(OneOf3::C(npc)) => assert_eq!(*npc, Npc),
+    ///     _ => unreachable!()
+    /// }
+    /// ```
+    fn downcast_one_of<Tuple>(self: Box<Self>) -> Result<Tuple::Boxed, Box<Self>>
+    where
+        Tuple: Candidates<Self>
+    {
+        Tuple::downcast_one_of(self)
+    }
+}
+impl<From> DowncastOneOf for From
+where
+    From: ?Sized {}
@@ -1,22 +1,27 @@
-use crate::IsObjOf;
+use crate::{AsAny, CastError, IsObjOf};
 
 use super::*;
 
 /// A trait for downcasting objects.
 /// 
+/// To downcast into the first match among several candidate structs, see [DowncastOneOf](DowncastOneOf).
+/// 
 /// # Examples
 /// ```rust
-/// use spellcast::{downcast::*};
+/// use spellcast::{downcast::*, impl_object, impl_is};
 /// use core::any::Any;
 /// 
 /// // Object trait must extend Any to support downcasts.
 /// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
 /// 
 /// #[derive(PartialEq, Debug)]
 /// struct Human;
 /// 
 /// impl PlayerObj for Human {}
 /// 
+/// impl_is!(Human: dyn PlayerObj);
+/// 
 /// let player = Box::new(Human) as Box<dyn PlayerObj>;
 /// 
 /// assert_eq!((&*player).downcast_ref(), Some(&Human));
@@ -29,17 +34,20 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::{downcast::*};
+    /// use spellcast::{downcast::*, impl_object, impl_is};
     /// use core::any::Any;
     /// 
     /// // Object trait must extend Any to support downcasts.
     /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
     /// 
     /// #[derive(PartialEq, Debug)]
     /// struct Human;
     /// 
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
     /// let player = Box::new(Human) as Box<dyn PlayerObj>;
     /// 
     /// assert_eq!((&*player).downcast_ref(), Some(&Human));
@@ -50,7 +58,7 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::{downcast::*};
+    /// use spellcast::{downcast::*, impl_object, impl_is};
     /// use core::any::Any;
     /// 
     /// // Object trait must extend Any to support downcasts.
@@ -58,6 +66,7 @@ where
     /// {
     ///     fn is_alive(&self) -> bool;
     /// }
+    /// impl_object!(PlayerObj);
     /// 
     /// struct Human
     /// {
@@ -72,6 +81,8 @@ where
     ///     }
     /// }
     /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// 
     /// let mut player = Box::new(Human {is_alive: true}) as Box<dyn PlayerObj>;
     /// 
     /// assert_eq!(player.is_alive(), true);
@@ -86,6 +97,71 @@ where
     /// assert_eq!(player.is_alive(), false);
     /// ```
     fn downcast_mut(self: &mut Self) -> Option<&mut To>;
+
+    /// Downcasts an object into a struct implementing the object's trait.
+    /// 
+    /// On failure, a [CastError](CastError) describing the failed cast is returned.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::{Any, TypeId};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+    /// 
+    /// let human: Result<&Human, _> = (&*player).downcast_ref_checked();
+    /// let error = human.unwrap_err();
+    /// assert_eq!(error.to_string(), format!("cannot cast `{}` ({:?}) into `{}`", error.source_type_name(), TypeId::of::<Bot>(), error.target_type_name()));
+    /// ```
+    fn downcast_ref_checked(self: &Self) -> Result<&To, CastError<&Self>>
+    where
+        Self: AsAny;
+
+    /// Downcasts an object into a struct implementing the object's trait.
+    /// 
+    /// On failure, a [CastError](CastError) describing the failed cast is returned.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_object, impl_is};
+    /// use core::any::{Any, TypeId};
+    /// 
+    /// // Object trait must extend Any to support downcasts.
+    /// trait PlayerObj: Any {}
+    /// impl_object!(PlayerObj);
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Human;
+    /// struct Bot;
+    /// 
+    /// impl PlayerObj for Human {}
+    /// impl PlayerObj for Bot {}
+    /// 
+    /// impl_is!(Human: dyn PlayerObj);
+    /// impl_is!(Bot: dyn PlayerObj);
+    /// 
+    /// let mut player = Box::new(Human) as Box<dyn PlayerObj>;
+    /// 
+    /// let human: &mut Human = (&mut *player).downcast_mut_checked().unwrap();
+    /// assert_eq!(human, &mut Human);
+    /// ```
+    fn downcast_mut_checked(self: &mut Self) -> Result<&mut To, CastError<&mut Self>>
+    where
+        Self: AsAny;
 }
 impl<'a, From, To> DowncastRef<To> for From
 where
@@ -100,4 +176,16 @@ where
     {
         To::downcast_from_mut(self)
     }
+    fn downcast_ref_checked(self: &Self) -> Result<&To, CastError<&Self>>
+    where
+        Self: AsAny
+    {
+        To::downcast_from_ref_checked(self)
+    }
+    fn downcast_mut_checked(self: &mut Self) -> Result<&mut To, CastError<&mut Self>>
+    where
+        Self: AsAny
+    {
+        To::downcast_from_mut_checked(self)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,161 @@
+use alloc::boxed::Box;
+
+use crate::{AsTid, Tid};
+
+use super::*;
+
+/// A trait for downcasting objects that may borrow data, like `dyn AstNode<'src>`, into structs of the same lifetime.
+/// 
+/// The object trait must extend [AsTid](AsTid), and the structs must implement [Tid](Tid) with [impl_tid](crate::impl_tid).
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{downcast::*, impl_tid, AsTid};
+/// 
+/// // Object trait must extend AsTid to support lifetime-aware downcasts.
+/// trait AstNode<'src>: AsTid<'src> {}
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Ident<'src>
+/// {
+///     name: &'src str
+/// }
+/// #[derive(PartialEq, Debug)]
+/// struct Number(i64);
+/// 
+/// impl<'src> AstNode<'src> for Ident<'src> {}
+/// impl<'src> AstNode<'src> for Number {}
+/// 
+/// impl_tid!(Ident<'src>);
+/// impl_tid!(Number);
+/// 
+/// let source = String::from("x");
+/// let node = Box::new(Ident {name: &source}) as Box<dyn AstNode<'_>>;
+/// 
+/// assert_eq!((*node).downcast_tid_ref::<Ident>(), Some(&Ident {name: "x"}));
+/// assert_eq!((*node).downcast_tid_ref::<Number>(), None);
+/// ```
+/// 
+/// The downcast struct keeps the lifetime of the object, so it cannot outlive the borrowed data.
+/// ```rust,compile_fail
+/// use spellcast::{downcast::*, impl_tid, AsTid};
+/// 
+/// trait AstNode<'src>: AsTid<'src> {}
+/// 
+/// struct Ident<'src>
+/// {
+///     name: &'src str
+/// }
+/// 
+/// impl<'src> AstNode<'src> for Ident<'src> {}
+/// 
+/// impl_tid!(Ident<'src>);
+/// 
+/// let ident: Box<Ident<'static>> = {
+///     let source = String::from("x");
+///     let node = Box::new(Ident {name: &source}) as Box<dyn AstNode<'_>>;
+///     node.downcast_tid::<Ident>().ok().unwrap()
+/// };
+/// ```
+pub trait DowncastTid<'a>: AsTid<'a>
+{
+    /// Checks if the object is of the struct.
+    fn is_tid<To>(self: &Self) -> bool
+    where
+        To: Tid<'a>
+    {
+        self.self_tid() == To::tid()
+    }
+
+    /// Downcasts a reference to the object into a reference to the struct.
+    fn downcast_tid_ref<To>(self: &Self) -> Option<&To>
+    where
+        To: Tid<'a>
+    {
+        if !self.is_tid::<To>()
+        {
+            return None
+        }
+        // SAFETY: The object is a To, as both share the same unique marker type and lifetime.
+        Some(unsafe {&*(self as *const Self as *const To)})
+    }
+
+    /// Downcasts a mutable reference to the object into a mutable reference to the struct.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_tid, AsTid};
+    /// 
+    /// trait AstNode<'src>: AsTid<'src> {}
+    /// 
+    /// struct Ident<'src>
+    /// {
+    ///     name: &'src str
+    /// }
+    /// 
+    /// impl<'src> AstNode<'src> for Ident<'src> {}
+    /// 
+    /// impl_tid!(Ident<'src>);
+    /// 
+    /// let (x, y) = (String::from("x"), String::from("y"));
+    /// let mut node = Box::new(Ident {name: &x}) as Box<dyn AstNode<'_>>;
+    /// 
+    /// (*node).downcast_tid_mut::<Ident>().unwrap().name = &y;
+    /// assert_eq!((*node).downcast_tid_ref::<Ident>().unwrap().name, "y");
+    /// ```
+    fn downcast_tid_mut<To>(self: &mut Self) -> Option<&mut To>
+    where
+        To: Tid<'a>
+    {
+        if !self.is_tid::<To>()
+        {
+            return None
+        }
+        // SAFETY: The object is a To, as both share the same unique marker type and lifetime.
+        Some(unsafe {&mut *(self as *mut Self as *mut To)})
+    }
+
+    /// Downcasts a boxed object into a boxed struct.
+    /// 
+    /// On failure, the original object is given back.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{downcast::*, impl_tid, AsTid};
+    /// 
+    /// trait AstNode<'src>: AsTid<'src> {}
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Ident<'src>
+    /// {
+    ///     name: &'src str
+    /// }
+    /// struct Number(i64);
+    /// 
+    /// impl<'src> AstNode<'src> for Ident<'src> {}
+    /// impl<'src> AstNode<'src> for Number {}
+    /// 
+    /// impl_tid!(Ident<'src>);
+    /// impl_tid!(Number);
+    /// 
+    /// let source = String::from("x");
+    /// let node = Box::new(Ident {name: &source}) as Box<dyn AstNode<'_>>;
+    /// 
+    /// let node = node.downcast_tid::<Number>().err().unwrap();
+    /// assert_eq!(*node.downcast_tid::<Ident>().ok().unwrap(), Ident {name: "x"});
+    /// ```
+    fn downcast_tid<To>(self: Box<Self>) -> Result<Box<To>, Box<Self>>
+    where
+        To: Tid<'a>
+    {
+        if !self.is_tid::<To>()
+        {
+            return Err(self)
+        }
+        // SAFETY: The object is a To, as both share the same unique marker type and lifetime, so the allocation has the layout of a To.
+        Ok(unsafe {Box::from_raw(Box::into_raw(self) as *mut To)})
+    }
+}
+impl<'a, From> DowncastTid<'a> for From
+where
+    From: AsTid<'a> + ?Sized {}
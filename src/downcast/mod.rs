@@ -3,7 +3,10 @@ moddef::moddef!(
         downcast_from_ref,
         downcast_from,
         downcast_ref,
-        downcast_
+        downcast_,
+        one_of,
+        downcast_one_of,
+        downcast_tid
     }
 );
 
@@ -0,0 +1,102 @@
+use alloc::boxed::Box;
+
+use crate::AsAny;
+
+use super::*;
+
+/// A tuple of candidate structs to downcast an object into, used by [DowncastOneOf](DowncastOneOf).
+/// 
+/// Implemented for tuples of up to 12 structs.
+pub trait Candidates<From>
+where
+    From: ?Sized
+{
+    /// The enum of references to each of the candidates.
+    type Ref<'a>
+    where
+        From: 'a;
+    /// The enum of mutable references to each of the candidates.
+    type Mut<'a>
+    where
+        From: 'a;
+    /// The enum of boxes of each of the candidates.
+    type Boxed;
+
+    /// Downcasts a reference into the first candidate that matches.
+    fn downcast_one_of_ref(from: &From) -> Option<Self::Ref<'_>>;
+    /// Downcasts a mutable reference into the first candidate that matches.
+    fn downcast_one_of_mut(from: &mut From) -> Option<Self::Mut<'_>>;
+    /// Downcasts a box into the first candidate that matches.
+    /// 
+    /// On failure, the original box is given back.
+    fn downcast_one_of(from: Box<From>) -> Result<Self::Boxed, Box<From>>;
+}
+
+macro_rules! impl_one_of {
+    ($n:literal $one_of:ident: $($t:ident),+) => {
+        #[doc = concat!("One of ", stringify!($n), " types, returned by [DowncastOneOf](DowncastOneOf) for a tuple of ", stringify!($n), " candidates.")]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub enum $one_of<$($t),+>
+        {
+            $($t($t)),+
+        }
+
+        impl<From, $($t),+> Candidates<From> for ($($t,)+)
+        where
+            From: AsAny + ?Sized,
+            $($t: DowncastFromRef<From> + 'static),+
+        {
+            type Ref<'a> = $one_of<$(&'a $t),+>
+            where
+                From: 'a;
+            type Mut<'a> = $one_of<$(&'a mut $t),+>
+            where
+                From: 'a;
+            type Boxed = $one_of<$(Box<$t>),+>;
+
+            fn downcast_one_of_ref(from: &From) -> Option<Self::Ref<'_>>
+            {
+                $(
+                    if let Some(vee) = $t::downcast_from_ref(from)
+                    {
+                        return Some($one_of::$t(vee))
+                    }
+                )+
+                None
+            }
+            fn downcast_one_of_mut(from: &mut From) -> Option<Self::Mut<'_>>
+            {
+                $(
+                    if $t::downcast_from_ref(&*from).is_some()
+                    {
+                        return $t::downcast_from_mut(from).map($one_of::$t)
+                    }
+                )+
+                None
+            }
+            fn downcast_one_of(from: Box<From>) -> Result<Self::Boxed, Box<From>>
+            {
+                $(
+                    if $t::downcast_from_ref(&*from).is_some()
+                    {
+                        return Ok($one_of::$t(from.into_any().downcast().unwrap()))
+                    }
+                )+
+                Err(from)
+            }
+        }
+    };
+}
+
+impl_one_of!(1 OneOf1: A);
+impl_one_of!(2 OneOf2: A, B);
+impl_one_of!(3 OneOf3: A, B, C);
+impl_one_of!(4 OneOf4: A, B, C, D);
+impl_one_of!(5 OneOf5: A, B, C, D, E);
+impl_one_of!(6 OneOf6: A, B, C, D, E, F);
+impl_one_of!(7 OneOf7: A, B, C, D, E, F, G);
+impl_one_of!(8 OneOf8: A, B, C, D, E, F, G, H);
+impl_one_of!(9 OneOf9: A, B, C, D, E, F, G, H, I);
+impl_one_of!(10 OneOf10: A, B, C, D, E, F, G, H, I, J);
+impl_one_of!(11 OneOf11: A, B, C, D, E, F, G, H, I, J, K);
+impl_one_of!(12 OneOf12: A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,97 @@
+use alloc::boxed::Box;
+
+use super::*;
+
+/// A trait for cloning objects behind a box, so that boxed trait objects can be cloned.
+/// 
+/// Implemented for every type implementing [Clone](Clone). Boxed objects are cloned with [clone_box](clone_box).
+/// 
+/// An object trait extending this gets [Clone](Clone) for its boxed trait object with `impl_object!(Trait with Clone)`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynClone, impl_object};
+/// use core::any::Any;
+/// 
+/// trait PlayerObj: Any + DynClone
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// impl_object!(PlayerObj with Clone);
+/// 
+/// #[derive(Clone)]
+/// struct Human
+/// {
+///     name: String
+/// }
+/// 
+/// impl PlayerObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         &self.name
+///     }
+/// }
+/// 
+/// let player = Box::new(Human {name: "Alice".to_string()}) as Box<dyn PlayerObj>;
+/// let clone = player.clone();
+/// 
+/// assert_eq!(clone.name(), "Alice");
+/// ```
+pub trait DynClone
+{
+    #[doc(hidden)]
+    fn __clone_raw(self: &Self, _: private::Private) -> *mut ();
+}
+impl<T> DynClone for T
+where
+    T: Clone
+{
+    fn __clone_raw(self: &Self, _: private::Private) -> *mut ()
+    {
+        Box::into_raw(Box::new(self.clone())) as *mut ()
+    }
+}
+
+/// Clones an object, possibly a trait object, into a new box.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynClone, clone_box};
+/// 
+/// trait ShapeObj: DynClone
+/// {
+///     fn area(&self) -> u32;
+/// }
+/// 
+/// #[derive(Clone)]
+/// struct Square(u32);
+/// 
+/// impl ShapeObj for Square
+/// {
+///     fn area(&self) -> u32
+///     {
+///         self.0*self.0
+///     }
+/// }
+/// 
+/// let shape = Box::new(Square(2)) as Box<dyn ShapeObj>;
+/// let clone: Box<dyn ShapeObj> = clone_box(&*shape);
+/// 
+/// assert_eq!(clone.area(), 4);
+/// ```
+pub fn clone_box<T>(from: &T) -> Box<T>
+where
+    T: DynClone + ?Sized
+{
+    let mut ptr = from as *const T;
+    let data_ptr = &mut ptr as *mut *const T as *mut *mut ();
+    // SAFETY: The data pointer comes first in a pointer, so only the data pointer is replaced, keeping the metadata of the object.
+    // This layout isn't guaranteed, so it is checked before writing.
+    // The new data pointer points to a fresh allocation of a clone of the object, which has the same concrete type.
+    unsafe {
+        assert_eq!(*data_ptr as *const (), from as *const T as *const (), "the data pointer must come first in a pointer");
+        *data_ptr = from.__clone_raw(private::Private);
+        Box::from_raw(ptr as *mut T)
+    }
+}
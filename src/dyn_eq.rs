@@ -0,0 +1,43 @@
+use core::any::Any;
+
+/// A trait for comparing objects of possibly different concrete types for equality.
+/// 
+/// Objects of different concrete types are never equal. Implemented for every type implementing [PartialEq](PartialEq).
+/// 
+/// An object trait extending this gets [PartialEq](PartialEq) for its trait object with `impl_object!(Trait with PartialEq)`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::DynEq;
+/// 
+/// trait ShapeObj: DynEq {}
+/// 
+/// #[derive(PartialEq)]
+/// struct Circle(u32);
+/// #[derive(PartialEq)]
+/// struct Square(u32);
+/// 
+/// impl ShapeObj for Circle {}
+/// impl ShapeObj for Square {}
+/// 
+/// let circle = Box::new(Circle(1)) as Box<dyn ShapeObj>;
+/// 
+/// assert!(circle.dyn_eq(&Circle(1)));
+/// assert!(!circle.dyn_eq(&Circle(2)));
+/// assert!(!circle.dyn_eq(&Square(1)));
+/// ```
+pub trait DynEq: Any
+{
+    /// Checks if the object is equal to another object, viewed as [Any](Any).
+    fn dyn_eq(self: &Self, other: &dyn Any) -> bool;
+}
+impl<T> DynEq for T
+where
+    T: PartialEq + Any
+{
+    fn dyn_eq(self: &Self, other: &dyn Any) -> bool
+    {
+        other.downcast_ref::<T>()
+            .is_some_and(|other| self == other)
+    }
+}
@@ -0,0 +1,46 @@
+use core::{any::{Any, TypeId}, hash::{Hash, Hasher}};
+
+/// A trait for hashing objects of possibly different concrete types.
+/// 
+/// The concrete type is hashed along with the value. Implemented for every type implementing [Hash](Hash).
+/// 
+/// An object trait extending this gets [Hash](Hash) for its trait object with `impl_object!(Trait with Hash)`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynTotalEq, DynHash, impl_object};
+/// use std::collections::HashSet;
+/// 
+/// trait ShapeObj: DynTotalEq + DynHash {}
+/// impl_object!(ShapeObj with PartialEq, Eq, Hash);
+/// 
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Circle(u32);
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Square(u32);
+/// 
+/// impl ShapeObj for Circle {}
+/// impl ShapeObj for Square {}
+/// 
+/// let mut shapes: HashSet<Box<dyn ShapeObj>> = HashSet::new();
+/// shapes.insert(Box::new(Circle(1)));
+/// shapes.insert(Box::new(Circle(1)));
+/// shapes.insert(Box::new(Square(1)));
+/// 
+/// assert_eq!(shapes.len(), 2);
+/// ```
+pub trait DynHash: Any
+{
+    /// Feeds the object, and its concrete type, into the given [Hasher](Hasher).
+    fn dyn_hash(self: &Self, state: &mut dyn Hasher);
+}
+impl<T> DynHash for T
+where
+    T: Hash + Any
+{
+    fn dyn_hash(self: &Self, mut state: &mut dyn Hasher)
+    {
+        TypeId::of::<T>().hash(&mut state);
+        self.hash(&mut state);
+    }
+}
@@ -0,0 +1,52 @@
+use core::any::Any;
+
+/// A trait for looking up, at runtime, which trait objects an object can be cast into.
+/// 
+/// Implemented with [impl_object!](crate::impl_object) for a struct and the list of traits it implements, e.g. `impl_object!(Human: dyn Debug, dyn Display)`.
+/// An object trait extending this gets the queries of [Implements](crate::Implements) for its trait objects.
+/// 
+/// # Safety
+/// 
+/// A pointer written into `to` must point to the given object, like an unsizing coercion of the reference does,
+/// since [Implements](crate::Implements) dereferences it for as long as the reference it was made from.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynImplements, Implements, impl_object};
+/// use core::fmt::{Debug, Display};
+/// 
+/// trait PlayerObj: DynImplements {}
+/// 
+/// #[derive(Debug)]
+/// struct Human;
+/// #[derive(Debug)]
+/// struct Bot;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl Display for Bot
+/// {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+///     {
+///         write!(f, "bot")
+///     }
+/// }
+/// 
+/// impl_object!(Human: dyn Debug);
+/// impl_object!(Bot: dyn Debug, dyn Display);
+/// 
+/// let players = [Box::new(Human) as Box<dyn PlayerObj>, Box::new(Bot)];
+/// 
+/// assert!(players[0].implements::<dyn Debug>());
+/// assert!(!players[0].implements::<dyn Display>());
+/// assert_eq!(players[1].as_trait::<dyn Display>().unwrap().to_string(), "bot");
+/// ```
+pub unsafe trait DynImplements: Any
+{
+    /// Writes a pointer to the object as the trait object `To` into `to`, when it is an `Option<*const To>` of a listed trait.
+    #[doc(hidden)]
+    fn __as_trait_ptr(self: &Self, to: &mut dyn Any);
+    /// Writes a pointer to the object as the trait object `To` into `to`, when it is an `Option<*mut To>` of a listed trait.
+    #[doc(hidden)]
+    fn __as_trait_ptr_mut(self: &mut Self, to: &mut dyn Any);
+}
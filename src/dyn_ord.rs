@@ -0,0 +1,47 @@
+use core::{any::{Any, TypeId}, cmp::Ordering};
+
+/// A trait for ordering objects of possibly different concrete types.
+/// 
+/// Objects of the same concrete type are ordered by value, and objects of different concrete types by their [TypeId](TypeId).
+/// Implemented for every type implementing [Ord](Ord).
+/// 
+/// An object trait extending this gets [PartialOrd](PartialOrd) and [Ord](Ord) for its trait object with `impl_object!(Trait with PartialOrd, Ord)`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynTotalEq, DynOrd, impl_object};
+/// 
+/// trait ShapeObj: DynTotalEq + DynOrd {}
+/// impl_object!(ShapeObj with PartialEq, Eq, PartialOrd, Ord);
+/// 
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// struct Circle(u32);
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// struct Square(u32);
+/// 
+/// impl ShapeObj for Circle {}
+/// impl ShapeObj for Square {}
+/// 
+/// let mut shapes: Vec<Box<dyn ShapeObj>> = vec![Box::new(Circle(2)), Box::new(Square(1)), Box::new(Circle(1))];
+/// shapes.sort();
+/// 
+/// assert!(*shapes[0] < *shapes[1] && *shapes[1] < *shapes[2]);
+/// ```
+pub trait DynOrd: Any
+{
+    /// Compares the object with another object, viewed as [Any](Any).
+    fn dyn_cmp(self: &Self, other: &dyn Any) -> Ordering;
+}
+impl<T> DynOrd for T
+where
+    T: Ord + Any
+{
+    fn dyn_cmp(self: &Self, other: &dyn Any) -> Ordering
+    {
+        match other.downcast_ref::<T>()
+        {
+            Some(other) => self.cmp(other),
+            None => TypeId::of::<T>().cmp(&other.type_id())
+        }
+    }
+}
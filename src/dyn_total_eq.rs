@@ -0,0 +1,37 @@
+use core::any::Any;
+
+use super::*;
+
+/// A marker trait for objects whose equality, given by [DynEq](DynEq), is an equivalence relation.
+/// 
+/// Implemented for every type implementing [Eq](Eq).
+/// 
+/// An object trait extending this gets [Eq](Eq) for its trait object with `impl_object!(Trait with PartialEq, Eq)`.
+/// Extending only [DynEq](DynEq) is not enough, since structs implementing the trait might only implement [PartialEq](PartialEq).
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynTotalEq, impl_object};
+/// 
+/// trait ShapeObj: DynTotalEq {}
+/// impl_object!(ShapeObj with PartialEq, Eq);
+/// 
+/// #[derive(PartialEq, Eq)]
+/// struct Circle(u32);
+/// 
+/// impl ShapeObj for Circle {}
+/// 
+/// fn same<T: Eq + ?Sized>(a: &T, b: &T) -> bool
+/// {
+///     a == b
+/// }
+/// 
+/// let a = Box::new(Circle(1)) as Box<dyn ShapeObj>;
+/// let b = Box::new(Circle(1)) as Box<dyn ShapeObj>;
+/// 
+/// assert!(same(&*a, &*b));
+/// ```
+pub trait DynTotalEq: DynEq {}
+impl<T> DynTotalEq for T
+where
+    T: Eq + Any {}
@@ -1,7 +1,36 @@
+use alloc::boxed::Box;
+
 use crate::upcast::Upcast;
 
 use super::*;
 
+/// A trait for casting between two trait objects, using a [DyncastObj](DyncastObj) as the medium.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{dyncast::*, impl_dyncast, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait EntityObj: Any {}
+/// impl_object!(EntityObj);
+/// trait PlayerObj: EntityObj {}
+/// impl_object!(PlayerObj);
+/// 
+/// impl_dyncast!(PlayerObj: EntityObj);
+/// 
+/// struct Human;
+/// 
+/// impl EntityObj for Human {}
+/// impl PlayerObj for Human {}
+/// 
+/// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+/// impl_is!(dyn PlayerObj: dyn EntityObj);
+/// 
+/// let player = Box::new(Human) as Box<dyn PlayerObj>;
+/// 
+/// let entity: &dyn EntityObj = (*player).dyncast_ref();
+/// let entity: Box<dyn EntityObj> = player.dyncast();
+/// ```
 pub trait Dyncast<To>: DyncastRef<To>
 where
     To: ?Sized
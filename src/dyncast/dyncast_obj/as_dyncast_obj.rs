@@ -12,7 +12,7 @@ where
 }
 impl<Medium, From, To> AsDyncastObj<From, To> for Medium
 where
-    Medium: DyncastObj<From, To, Obj: Upcast<To> + ?Sized> + TryAsDyncastObj<From, To> + Upcast<Self::Obj> + ?Sized,
+    Medium: DyncastObj<From, To, Obj: Upcast<To>> + TryAsDyncastObj<From, To> + Upcast<Self::Obj> + ?Sized,
     From: ?Sized,
     To: ?Sized
 {
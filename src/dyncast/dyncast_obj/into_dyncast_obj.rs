@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::upcast::Upcast;
 
 use super::*;
@@ -11,7 +13,7 @@ where
 }
 impl<Medium, From, To> IntoDyncastObj<From, To> for Medium
 where
-    Medium: DyncastObj<From, To, Obj: Upcast<To> + ?Sized> + Upcast<Self::Obj> + AsDyncastObj<From, To> + TryIntoDyncastObj<From, To, From> + ?Sized,
+    Medium: DyncastObj<From, To, Obj: Upcast<To>> + Upcast<Self::Obj> + AsDyncastObj<From, To> + TryIntoDyncastObj<From, To, From> + ?Sized,
     From: ?Sized,
     To: ?Sized
 {
@@ -12,7 +12,7 @@ where
 }
 impl<Medium, From, To> TryAsDyncastObj<From, To> for Medium
 where
-    Medium: TryUpcastRef<Self::Obj> + DyncastObj<From, To, Obj: TryUpcastRef<To> + ?Sized> + ?Sized,
+    Medium: TryUpcastRef<Self::Obj> + DyncastObj<From, To, Obj: TryUpcastRef<To>> + ?Sized,
     From: ?Sized,
     To: ?Sized
 {
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::upcast::TryUpcast;
 
 use super::*;
@@ -12,7 +14,7 @@ where
 }
 impl<Medium, From, To, Alt> TryIntoDyncastObj<From, To, Alt> for Medium
 where
-    Medium: TryUpcast<Self::Obj, Alt> + TryAsDyncastObj<From, To> + DyncastObj<From, To, Obj: TryUpcast<To, Alt> + ?Sized> + Is<Alt> + ?Sized,
+    Medium: TryUpcast<Self::Obj, Alt> + TryAsDyncastObj<From, To> + DyncastObj<From, To, Obj: TryUpcast<To, Alt>> + Is<Alt> + ?Sized,
     From: ?Sized,
     To: ?Sized,
     Alt: ?Sized
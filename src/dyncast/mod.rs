@@ -10,20 +10,113 @@ moddef::moddef!(
 
 use super::*;
 
+/// Implements dyncasting from the trait object of a subtrait into the trait object of its supertrait.
+/// 
+/// With `reverse` after the traits, it also implements fallibly dyncasting back from the supertrait object into the subtrait object.
+/// The concrete type behind the supertrait object is only known at runtime, so this goes through its table of [DynImplements](crate::DynImplements),
+/// which the supertrait must extend, and fails for structs that don't list the subtrait with [impl_object](crate::impl_object).
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{dyncast::*, impl_dyncast, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait EntityObj: Any
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// impl_object!(EntityObj);
+/// trait PlayerObj: EntityObj {}
+/// impl_object!(PlayerObj);
+/// 
+/// impl_dyncast!(PlayerObj: EntityObj);
+/// 
+/// struct Human;
+/// 
+/// impl EntityObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "human"
+///     }
+/// }
+/// impl PlayerObj for Human {}
+/// 
+/// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+/// impl_is!(dyn PlayerObj: dyn EntityObj);
+/// 
+/// let player = Box::new(Human) as Box<dyn PlayerObj>;
+/// 
+/// let entity: Box<dyn EntityObj> = player.dyncast();
+/// assert_eq!(entity.name(), "human");
+/// ```
+/// 
+/// ```rust
+/// use spellcast::{dyncast::*, impl_dyncast, impl_object, impl_is, DynImplements};
+/// 
+/// trait EntityObj: DynImplements {}
+/// impl_object!(EntityObj);
+/// trait PlayerObj: EntityObj {}
+/// impl_object!(PlayerObj);
+/// 
+/// impl_dyncast!(PlayerObj: EntityObj, reverse);
+/// 
+/// struct Human;
+/// struct Rock;
+/// 
+/// impl EntityObj for Human {}
+/// impl PlayerObj for Human {}
+/// impl EntityObj for Rock {}
+/// 
+/// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+/// impl_is!(dyn PlayerObj: dyn EntityObj);
+/// impl_object!(Human: dyn PlayerObj);
+/// impl_object!(Rock:);
+/// 
+/// let entity = Box::new(Human) as Box<dyn EntityObj>;
+/// 
+/// let player: Option<&dyn PlayerObj> = (*entity).try_dyncast_ref();
+/// assert!(player.is_some());
+/// 
+/// let entity = Box::new(Rock) as Box<dyn EntityObj>;
+/// 
+/// let player: Result<Box<dyn PlayerObj>, Box<dyn EntityObj>> = entity.try_dyncast();
+/// assert!(player.is_err());
+/// ```
 #[macro_export]
 macro_rules! impl_dyncast {
-    ($sub:path : $super:path) => {
-        impl<Medium> DyncastObj<dyn $sub, dyn $super> for Medium
-        where
-            Medium: $sub + $super + spellcast::Is<dyn $sub> + spellcast::Is<dyn $super> + ?Sized,
+    ($sub:path : $super:path, reverse) => {
+        spellcast::impl_dyncast!($sub : $super);
+        impl spellcast::dyncast::TryDyncastRef<dyn $sub> for dyn $super
         {
-            type Obj = dyn $sub;
+            fn try_dyncast_ref(self: &Self) -> Option<&(dyn $sub + 'static)>
+            {
+                spellcast::Implements::as_trait::<dyn $sub>(self)
+            }
+            fn try_dyncast_mut(self: &mut Self) -> Option<&mut (dyn $sub + 'static)>
+            {
+                spellcast::Implements::as_trait_mut::<dyn $sub>(self)
+            }
+        }
+        impl spellcast::dyncast::TryDyncast<dyn $sub, dyn $super> for dyn $super
+        {
+            fn try_dyncast(self: spellcast::alloc::boxed::Box<Self>) -> Result<spellcast::alloc::boxed::Box<dyn $sub>, spellcast::alloc::boxed::Box<dyn $super>>
+            {
+                let object = spellcast::alloc::boxed::Box::into_raw(self);
+                // SAFETY: The pointer comes from the box, and `DynImplements` guarantees that the subtrait object points to the same object,
+                // so exactly one box is made from the allocation again.
+                match spellcast::Implements::as_trait_mut::<dyn $sub>(unsafe {&mut *object})
+                {
+                    Some(sub) => Ok(unsafe {spellcast::alloc::boxed::Box::from_raw(sub as *mut dyn $sub)}),
+                    None => Err(unsafe {spellcast::alloc::boxed::Box::from_raw(object)})
+                }
+            }
         }
-        impl<Medium> DyncastObj<dyn $super, dyn $sub> for Medium
-        where
-            Medium: $sub + $super + spellcast::Is<dyn $sub> + spellcast::Is<dyn $super> + ?Sized,
+    };
+    ($sub:path : $super:path) => {
+        impl spellcast::dyncast::DyncastObj<dyn $sub, dyn $super> for dyn $sub
         {
             type Obj = dyn $sub;
         }
     };
-}
\ No newline at end of file
+}
@@ -1,7 +1,75 @@
+use alloc::boxed::Box;
+
 use crate::upcast::TryUpcast;
 
 use super::*;
 
+/// A trait for fallibly casting between two trait objects, using a [DyncastObj](DyncastObj) as the medium.
+/// 
+/// On failure, the original object is given back.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{dyncast::*, impl_dyncast, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait EntityObj: Any {}
+/// impl_object!(EntityObj);
+/// trait PlayerObj: EntityObj {}
+/// impl_object!(PlayerObj);
+/// 
+/// impl_dyncast!(PlayerObj: EntityObj);
+/// 
+/// struct Human;
+/// 
+/// impl EntityObj for Human {}
+/// impl PlayerObj for Human {}
+/// 
+/// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+/// impl_is!(dyn PlayerObj: dyn EntityObj);
+/// 
+/// let player = Box::new(Human) as Box<dyn PlayerObj>;
+/// 
+/// let entity: Result<Box<dyn EntityObj>, Box<dyn PlayerObj>> = player.try_dyncast();
+/// assert!(entity.is_ok());
+/// ```
+/// 
+/// Casting from a supertrait object back into a subtrait object needs the concrete type,
+/// which is looked up at runtime in its table of [DynImplements](crate::DynImplements), when implemented with `impl_dyncast!(Sub: Super, reverse)`.
+/// 
+/// ```rust
+/// use spellcast::{dyncast::*, impl_dyncast, impl_object, impl_is, DynImplements};
+/// 
+/// trait EntityObj: DynImplements {}
+/// impl_object!(EntityObj);
+/// trait PlayerObj: EntityObj {}
+/// impl_object!(PlayerObj);
+/// 
+/// impl_dyncast!(PlayerObj: EntityObj, reverse);
+/// 
+/// struct Human;
+/// struct Rock;
+/// 
+/// impl EntityObj for Human {}
+/// impl PlayerObj for Human {}
+/// impl EntityObj for Rock {}
+/// 
+/// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+/// impl_is!(Rock: dyn EntityObj);
+/// impl_is!(dyn PlayerObj: dyn EntityObj);
+/// impl_object!(Human: dyn PlayerObj);
+/// impl_object!(Rock:);
+/// 
+/// let entity = Box::new(Rock) as Box<dyn EntityObj>;
+/// 
+/// let player: Result<Box<dyn PlayerObj>, Box<dyn EntityObj>> = entity.try_dyncast();
+/// assert!(player.is_err());
+/// 
+/// let entity = Box::new(Human) as Box<dyn EntityObj>;
+/// 
+/// let player: Result<Box<dyn PlayerObj>, Box<dyn EntityObj>> = entity.try_dyncast();
+/// assert!(player.is_ok());
+/// ```
 pub trait TryDyncast<To, Obj>: Is<Obj> + TryDyncastRef<To>
 where
     To: ?Sized,
@@ -13,7 +81,8 @@ impl<From, To, Obj> TryDyncast<To, Obj> for From
 where
     From: TryDyncastRef<To> + TryIntoDyncastObj<From, To, Obj> + ?Sized,
     From::Obj: TryUpcast<To, Obj>,
-    To: ?Sized
+    To: ?Sized,
+    Obj: ?Sized
 {
     fn try_dyncast(self: Box<Self>) -> Result<Box<To>, Box<Obj>>
     {
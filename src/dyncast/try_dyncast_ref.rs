@@ -2,7 +2,7 @@ use crate::upcast::TryUpcastRef;
 
 use super::*;
 
-pub trait TryDyncastRef<To>: TryAsDyncastObj<Self, To>
+pub trait TryDyncastRef<To>
 where
     To: ?Sized
 {
@@ -0,0 +1,71 @@
+use super::*;
+
+/// A trait for asking, at runtime, whether an object implements a trait, and casting it into that trait object.
+/// 
+/// Implemented for every [DynImplements](DynImplements), including the trait objects of object traits extending it.
+/// Unlike [TryUpcastRef](crate::upcast::TryUpcastRef), this works for trait objects whose concrete type has been erased.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynImplements, Implements, impl_object};
+/// use core::fmt::Debug;
+/// 
+/// trait EntityObj: DynImplements {}
+/// 
+/// #[derive(Debug)]
+/// struct Human
+/// {
+///     hp: u32
+/// }
+/// struct Rock;
+/// 
+/// impl EntityObj for Human {}
+/// impl EntityObj for Rock {}
+/// 
+/// impl_object!(Human: dyn Debug);
+/// impl_object!(Rock:);
+/// 
+/// let mut entity = Box::new(Human {hp: 10}) as Box<dyn EntityObj>;
+/// 
+/// assert_eq!(format!("{:?}", entity.as_trait::<dyn Debug>().unwrap()), "Human { hp: 10 }");
+/// assert!(entity.as_trait_mut::<dyn Debug>().is_some());
+/// 
+/// let rock = Box::new(Rock) as Box<dyn EntityObj>;
+/// 
+/// assert!(!rock.implements::<dyn Debug>());
+/// ```
+pub trait Implements: DynImplements
+{
+    /// Checks if the object implements the trait object `To`.
+    fn implements<To>(self: &Self) -> bool
+    where
+        To: ?Sized + 'static
+    {
+        self.as_trait::<To>().is_some()
+    }
+
+    /// Casts a reference into the trait object `To`, if the object implements it.
+    fn as_trait<To>(self: &Self) -> Option<&To>
+    where
+        To: ?Sized + 'static
+    {
+        let mut to: Option<*const To> = None;
+        self.__as_trait_ptr(&mut to);
+        // SAFETY: The pointer points to the object, as required by `DynImplements`, and so is valid for the lifetime of the reference.
+        to.map(|to| unsafe {&*to})
+    }
+
+    /// Casts a mutable reference into the trait object `To`, if the object implements it.
+    fn as_trait_mut<To>(self: &mut Self) -> Option<&mut To>
+    where
+        To: ?Sized + 'static
+    {
+        let mut to: Option<*mut To> = None;
+        self.__as_trait_ptr_mut(&mut to);
+        // SAFETY: The pointer points to the object, as required by `DynImplements`, and so is valid and unaliased for the lifetime of the mutable reference.
+        to.map(|to| unsafe {&mut *to})
+    }
+}
+impl<T> Implements for T
+where
+    T: DynImplements + ?Sized {}
@@ -1,13 +1,23 @@
-use std::marker::Unsize;
+use core::pin::Pin;
+
+use alloc::{boxed::Box, rc::{self, Rc}, sync::{self, Arc}};
 
 use super::*;
 
 /// A trait which signifies that Self, a struct, implements a trait, given as a trait-object in the generic
 /// 
+/// With the `nightly` feature, this is implemented for every type that unsizes into the trait-object.
+/// Without it, it must be implemented with [impl_is](crate::impl_is) or [impl_object](crate::impl_object).
+/// 
+/// # Safety
+/// 
+/// The pointer returned by [as_obj_ptr](Is::as_obj_ptr) and [as_obj_ptr_mut](Is::as_obj_ptr_mut) must point to the same object as the given pointer,
+/// like an unsizing coercion does.
+/// 
 /// # Example
 /// 
 /// ```rust
-/// use spellcast::Is;
+/// use spellcast::{Is, impl_is};
 /// 
 /// trait A {}
 /// trait B {}
@@ -15,20 +25,153 @@ use super::*;
 /// struct X;
 /// 
 /// impl A for X {}
+/// impl_is!(X: dyn A);
 /// 
 /// static_assertions::assert_impl_one!(X: Is<dyn A>);
 /// ```
-pub unsafe trait Is<Trait>: Unsize<Trait>
+pub unsafe trait Is<Trait>: private::MaybeUnsize<Trait>
 where
-    Trait: ?Sized {}
+    Trait: ?Sized
+{
+    /// Casts a pointer into a pointer to the trait-object.
+    fn as_obj_ptr(from: *const Self) -> *const Trait;
+    /// Casts a mutable pointer into a mutable pointer to the trait-object.
+    fn as_obj_ptr_mut(from: *mut Self) -> *mut Trait;
+
+    /// Casts a reference into a reference to the trait-object.
+    fn as_obj(self: &Self) -> &Trait
+    {
+        // SAFETY: The pointer points to the same object, and so is valid for the same lifetime.
+        unsafe {&*Self::as_obj_ptr(self)}
+    }
+    /// Casts a mutable reference into a mutable reference to the trait-object.
+    fn as_obj_mut(self: &mut Self) -> &mut Trait
+    {
+        // SAFETY: The pointer points to the same object, and so is valid for the same lifetime.
+        unsafe {&mut *Self::as_obj_ptr_mut(self)}
+    }
+    /// Casts a box into a box of the trait-object.
+    fn into_obj(self: Box<Self>) -> Box<Trait>
+    {
+        // SAFETY: The pointer points to the same allocation, just like with an unsizing coercion.
+        unsafe {Box::from_raw(Self::as_obj_ptr_mut(Box::into_raw(self)))}
+    }
+    /// Casts a pinned box into a pinned box of the trait-object.
+    fn into_obj_pin(self: Pin<Box<Self>>) -> Pin<Box<Trait>>
+    {
+        // SAFETY: The object itself is never moved, and is immediately pinned again.
+        unsafe {Box::into_pin(Self::into_obj(Pin::into_inner_unchecked(self)))}
+    }
+    /// Casts a reference-counted pointer into a reference-counted pointer to the trait-object.
+    fn into_obj_rc(self: Rc<Self>) -> Rc<Trait>
+    {
+        // SAFETY: The pointer points to the same allocation, just like with an unsizing coercion.
+        unsafe {Rc::from_raw(Self::as_obj_ptr(Rc::into_raw(self)))}
+    }
+    /// Casts an atomically reference-counted pointer into an atomically reference-counted pointer to the trait-object.
+    fn into_obj_arc(self: Arc<Self>) -> Arc<Trait>
+    {
+        // SAFETY: The pointer points to the same allocation, just like with an unsizing coercion.
+        unsafe {Arc::from_raw(Self::as_obj_ptr(Arc::into_raw(self)))}
+    }
+    /// Casts a weak pointer into a weak pointer to the trait-object.
+    fn into_obj_weak(from: rc::Weak<Self>) -> rc::Weak<Trait>
+    {
+        // SAFETY: The pointer points to the same allocation, just like with an unsizing coercion.
+        unsafe {rc::Weak::from_raw(Self::as_obj_ptr(rc::Weak::into_raw(from)))}
+    }
+    /// Casts an atomic weak pointer into an atomic weak pointer to the trait-object.
+    fn into_obj_weak_arc(from: sync::Weak<Self>) -> sync::Weak<Trait>
+    {
+        // SAFETY: The pointer points to the same allocation, just like with an unsizing coercion.
+        unsafe {sync::Weak::from_raw(Self::as_obj_ptr(sync::Weak::into_raw(from)))}
+    }
+}
+#[cfg(feature = "nightly")]
 unsafe impl<Trait, T> Is<Trait> for T
 where
     Trait: ?Sized,
-    T: std::marker::Unsize<Trait> + ?Sized {}
+    T: core::marker::Unsize<Trait> + ?Sized
+{
+    fn as_obj_ptr(from: *const Self) -> *const Trait
+    {
+        from
+    }
+    fn as_obj_ptr_mut(from: *mut Self) -> *mut Trait
+    {
+        from
+    }
+}
 pub trait IsObjOf<Type>: private::IsObjOf<Type>
 where
     Type: ?Sized {}
 impl<Type, Trait> IsObjOf<Type> for Trait
 where
     Type: Is<Trait> + ?Sized,
-    Trait: ?Sized {}
\ No newline at end of file
+    Trait: ?Sized {}
+
+/// Implements [Is](Is) for a type, given one or more trait-objects it can be unsized into.
+/// 
+/// With the `nightly` feature, [Is](Is) is already implemented for all such types, so this only asserts that it is.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use spellcast::{impl_is, Is};
+/// 
+/// trait A {}
+/// trait B: A {}
+/// 
+/// struct X;
+/// 
+/// impl A for X {}
+/// impl B for X {}
+/// 
+/// impl_is!(X: dyn A, dyn B);
+/// impl_is!(dyn B: dyn A);
+/// 
+/// static_assertions::assert_impl_all!(X: Is<dyn A>, Is<dyn B>);
+/// static_assertions::assert_impl_all!(dyn B: Is<dyn A>);
+/// ```
+#[macro_export]
+macro_rules! impl_is {
+    ($type:ty : $($trait:ty),+ $(,)?) => {
+        $(
+            spellcast::__impl_is!($type: $trait);
+        )+
+    };
+}
+
+#[cfg(feature = "nightly")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_is {
+    ($type:ty : $trait:ty) => {
+        const _: () = {
+            fn assert_is<T, Trait>()
+            where
+                T: spellcast::Is<Trait> + ?Sized,
+                Trait: ?Sized {}
+            let _ = assert_is::<$type, $trait>;
+        };
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_is {
+    ($type:ty : $trait:ty) => {
+        unsafe impl spellcast::Is<$trait> for $type
+        {
+            fn as_obj_ptr(from: *const Self) -> *const $trait
+            {
+                from
+            }
+            fn as_obj_ptr_mut(from: *mut Self) -> *mut $trait
+            {
+                from
+            }
+        }
+    };
+}
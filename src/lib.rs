@@ -1,27 +1,51 @@
-#![feature(unsize)]
-#![feature(coerce_unsized)]
-#![feature(associated_type_bounds)]
-#![feature(trait_alias)]
-#![feature(new_uninit)]
-#![feature(decl_macro)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#![feature(specialization)]
+#![cfg_attr(feature = "nightly", feature(unsize))]
+#![cfg_attr(feature = "nightly", feature(coerce_unsized))]
+#![cfg_attr(feature = "nightly", feature(associated_type_bounds))]
+#![cfg_attr(feature = "nightly", feature(trait_alias))]
+#![cfg_attr(feature = "nightly", feature(new_uninit))]
+#![cfg_attr(feature = "nightly", feature(decl_macro))]
+#![cfg_attr(feature = "nightly", feature(ptr_metadata))]
+
+#![cfg_attr(feature = "nightly", feature(specialization))]
+
+#[doc(hidden)]
+pub extern crate alloc;
 
 moddef::moddef!(
     pub mod {
         downcast,
         upcast,
         veecast,
-        //dyncast,
-        convert
+        dyncast for cfg(feature = "dyncast"),
+        convert,
+        registry for cfg(feature = "std")
     },
     flat(pub) mod {
         is,
         as_any,
-        object
+        object,
+        assert_is for cfg(feature = "nightly"),
+        cast_error,
+        match_cast,
+        tid,
+        dyn_eq,
+        dyn_total_eq,
+        dyn_hash,
+        dyn_ord,
+        dyn_clone,
+        trait_map,
+        dyn_implements,
+        implements,
+        metadata for cfg(feature = "nightly"),
+        thin_box for cfg(feature = "nightly")
     }
 );
 
+#[cfg(feature = "derive")]
+pub use spellcast_derive::object;
+
 #[cfg(test)]
 mod tests {
     #[allow(unused)]
@@ -35,9 +59,10 @@ mod tests {
 
 mod private
 {
-    use std::marker::Unsize;
-
     use crate::Is;
+
+    /// Keeps hidden methods from being called or implemented outside of the crate.
+    pub struct Private;
     
     pub trait IsObjOf<Type>
     where
@@ -46,4 +71,24 @@ mod private
     where
         Type: Is<Trait> + ?Sized,
         Trait: ?Sized {}
+
+    #[cfg(feature = "nightly")]
+    pub trait MaybeUnsize<Trait>: core::marker::Unsize<Trait>
+    where
+        Trait: ?Sized {}
+    #[cfg(feature = "nightly")]
+    impl<T, Trait> MaybeUnsize<Trait> for T
+    where
+        T: core::marker::Unsize<Trait> + ?Sized,
+        Trait: ?Sized {}
+
+    #[cfg(not(feature = "nightly"))]
+    pub trait MaybeUnsize<Trait>
+    where
+        Trait: ?Sized {}
+    #[cfg(not(feature = "nightly"))]
+    impl<T, Trait> MaybeUnsize<Trait> for T
+    where
+        T: ?Sized,
+        Trait: ?Sized {}
 }
\ No newline at end of file
@@ -0,0 +1,244 @@
+use alloc::boxed::Box;
+
+use crate::{downcast::DowncastFromRef, upcast::Upcast};
+
+use super::*;
+
+/// Pattern-matches an object against structs and trait-objects, in order.
+/// 
+/// The object can be a `&dyn Trait`, a `&mut dyn Trait` or a `Box<dyn Trait>`.
+/// Complex expressions must be wrapped in parentheses.
+/// 
+/// The arms are:
+/// - `name: Struct => ...`, which downcasts the object into a struct.
+/// - `dyn Trait via Struct as name => ...`, which veecasts the object into another trait-object, using a mutual struct.
+/// - `_ => ...` or `name => ...`, the mandatory fallback arm, which is given back the original object.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{match_cast, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// // Object trait must extend Any to support downcasts.
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// trait Renderable
+/// {
+///     fn render(&self) -> String;
+/// }
+/// 
+/// struct Human
+/// {
+///     name: String
+/// }
+/// struct Bot;
+/// struct Npc;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl PlayerObj for Npc {}
+/// impl Renderable for Bot
+/// {
+///     fn render(&self) -> String
+///     {
+///         "bot".to_string()
+///     }
+/// }
+/// 
+/// fn describe(player: &dyn PlayerObj) -> String
+/// {
+///     match_cast!(player {
+///         human: Human => human.name.clone(),
+///         dyn Renderable via Bot as renderable => renderable.render(),
+///         _ => "unknown".to_string()
+///     })
+/// }
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj, dyn Renderable);
+/// impl_is!(Npc: dyn PlayerObj);
+/// 
+/// assert_eq!(describe(&Human {name: "Alice".to_string()}), "Alice");
+/// assert_eq!(describe(&Bot), "bot");
+/// assert_eq!(describe(&Npc), "unknown");
+/// ```
+/// 
+/// With boxes, the fallback arm gets the original box back.
+/// ```rust
+/// use spellcast::{match_cast, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// // Object trait must extend Any to support downcasts.
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// 
+/// struct Human;
+/// struct Bot;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// 
+/// let player: Box<dyn PlayerObj> = match_cast!(player {
+///     _human: Human => unreachable!(),
+///     player => player
+/// });
+/// 
+/// match_cast!(player {
+///     bot: Bot => {
+///         let _: Box<Bot> = bot;
+///     },
+///     _ => unreachable!()
+/// });
+/// ```
+#[macro_export]
+macro_rules! match_cast {
+    ($obj:tt { $($arms:tt)* }) => {
+        {
+            #[allow(unused_parens)]
+            let obj = $obj;
+            spellcast::match_cast!(@arms obj; $($arms)*)
+        }
+    };
+    (@arms $obj:ident; dyn $($trait:ident)::+ $(<$($arg:ty),* $(,)?>)? via $struct:ty as $bind:ident => $body:expr $(, $($rest:tt)*)?) => {
+        match spellcast::MatchCast::match_veecast::<$struct, dyn $($trait)::+ $(<$($arg),*>)?>($obj)
+        {
+            Ok($bind) => $body,
+            Err($obj) => spellcast::match_cast!(@arms $obj; $($($rest)*)?)
+        }
+    };
+    (@arms $obj:ident; $bind:ident : $to:ty => $body:expr $(, $($rest:tt)*)?) => {
+        match spellcast::MatchCast::match_downcast::<$to>($obj)
+        {
+            Ok($bind) => $body,
+            Err($obj) => spellcast::match_cast!(@arms $obj; $($($rest)*)?)
+        }
+    };
+    (@arms $obj:ident; _ => $body:expr $(,)?) => {
+        {
+            let _ = $obj;
+            $body
+        }
+    };
+    (@arms $obj:ident; $bind:ident => $body:expr $(,)?) => {
+        {
+            let $bind = $obj;
+            $body
+        }
+    };
+    (@arms $obj:ident;) => {
+        compile_error!("match_cast! requires a fallback arm, `_ => ...`")
+    };
+}
+
+/// A pointer to an object that can be pattern-matched with [match_cast](crate::match_cast).
+/// 
+/// Implemented for `&From`, `&mut From` and `Box<From>`. On failure, the pointer is given back as-is.
+pub trait MatchCast: Sized
+{
+    /// The object pointed to.
+    type Obj: ?Sized;
+    /// The same kind of pointer, pointing to the cast object.
+    type Target<To>
+    where
+        To: ?Sized + 'static;
+
+    /// Downcasts the object into a struct.
+    fn match_downcast<To>(self) -> Result<Self::Target<To>, Self>
+    where
+        To: DowncastFromRef<Self::Obj> + 'static;
+    /// Casts the object into another trait-object, using a mutual struct.
+    fn match_veecast<Struct, To>(self) -> Result<Self::Target<To>, Self>
+    where
+        Struct: DowncastFromRef<Self::Obj> + Upcast<To> + 'static,
+        To: ?Sized + 'static;
+}
+impl<'a, From> MatchCast for &'a From
+where
+    From: ?Sized
+{
+    type Obj = From;
+    type Target<To> = &'a To
+    where
+        To: ?Sized + 'static;
+
+    fn match_downcast<To>(self) -> Result<Self::Target<To>, Self>
+    where
+        To: DowncastFromRef<Self::Obj> + 'static
+    {
+        To::downcast_from_ref(self).ok_or(self)
+    }
+    fn match_veecast<Struct, To>(self) -> Result<Self::Target<To>, Self>
+    where
+        Struct: DowncastFromRef<Self::Obj> + Upcast<To> + 'static,
+        To: ?Sized + 'static
+    {
+        Struct::downcast_from_ref(self).map(|vee| vee.upcast_ref()).ok_or(self)
+    }
+}
+impl<'a, From> MatchCast for &'a mut From
+where
+    From: ?Sized
+{
+    type Obj = From;
+    type Target<To> = &'a mut To
+    where
+        To: ?Sized + 'static;
+
+    fn match_downcast<To>(self) -> Result<Self::Target<To>, Self>
+    where
+        To: DowncastFromRef<Self::Obj> + 'static
+    {
+        if To::downcast_from_ref(&*self).is_none()
+        {
+            return Err(self)
+        }
+        Ok(To::downcast_from_mut(self).unwrap())
+    }
+    fn match_veecast<Struct, To>(self) -> Result<Self::Target<To>, Self>
+    where
+        Struct: DowncastFromRef<Self::Obj> + Upcast<To> + 'static,
+        To: ?Sized + 'static
+    {
+        if Struct::downcast_from_ref(&*self).is_none()
+        {
+            return Err(self)
+        }
+        Ok(Struct::downcast_from_mut(self).unwrap().upcast_mut())
+    }
+}
+impl<From> MatchCast for Box<From>
+where
+    From: AsAny + ?Sized
+{
+    type Obj = From;
+    type Target<To> = Box<To>
+    where
+        To: ?Sized + 'static;
+
+    fn match_downcast<To>(self) -> Result<Self::Target<To>, Self>
+    where
+        To: DowncastFromRef<Self::Obj> + 'static
+    {
+        if To::downcast_from_ref(&*self).is_none()
+        {
+            return Err(self)
+        }
+        Ok(self.into_any().downcast().unwrap())
+    }
+    fn match_veecast<Struct, To>(self) -> Result<Self::Target<To>, Self>
+    where
+        Struct: DowncastFromRef<Self::Obj> + Upcast<To> + 'static,
+        To: ?Sized + 'static
+    {
+        if Struct::downcast_from_ref(&*self).is_none()
+        {
+            return Err(self)
+        }
+        Ok(self.into_any().downcast::<Struct>().unwrap().upcast())
+    }
+}
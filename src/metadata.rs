@@ -0,0 +1,171 @@
+use core::ptr::{self, DynMetadata, Pointee};
+
+use super::*;
+
+/// Gets the vtable of a trait object.
+/// 
+/// # Examples
+/// ```rust
+/// #![feature(ptr_metadata)]
+/// 
+/// use spellcast::vtable_of;
+/// 
+/// trait PlayerObj {}
+/// 
+/// struct Human
+/// {
+///     hp: u32
+/// }
+/// 
+/// impl PlayerObj for Human {}
+/// 
+/// let vtable = vtable_of(&Human {hp: 10} as &dyn PlayerObj);
+/// 
+/// assert_eq!(vtable.size_of(), 4);
+/// assert_eq!(vtable.align_of(), 4);
+/// ```
+pub fn vtable_of<Trait>(obj: &Trait) -> DynMetadata<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    ptr::metadata(obj)
+}
+
+/// Gets the vtable of the struct `Struct` as the trait object `Trait`, without needing an instance of it.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{from_raw_parts, vtable_for};
+/// 
+/// trait PlayerObj
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// 
+/// struct Human;
+/// 
+/// impl PlayerObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "human"
+///     }
+/// }
+/// 
+/// let vtable = vtable_for::<Human, dyn PlayerObj>();
+/// 
+/// let human = Human;
+/// 
+/// // SAFETY: The data pointer points to a `Human`, which the vtable was made for.
+/// let player = unsafe {from_raw_parts(&human as *const Human as *const (), vtable)};
+/// assert_eq!(player.name(), "human");
+/// ```
+pub fn vtable_for<Struct, Trait>() -> DynMetadata<Trait>
+where
+    Struct: Is<Trait>,
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    ptr::metadata(Struct::as_obj_ptr(ptr::null::<Struct>()))
+}
+
+/// Gets the size of the value behind a trait object, from its vtable.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::size_of_val_dyn;
+/// 
+/// trait PlayerObj {}
+/// 
+/// struct Human
+/// {
+///     hp: u32
+/// }
+/// 
+/// impl PlayerObj for Human {}
+/// 
+/// assert_eq!(size_of_val_dyn(&Human {hp: 10} as &dyn PlayerObj), 4);
+/// ```
+pub fn size_of_val_dyn<Trait>(obj: &Trait) -> usize
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    vtable_of(obj).size_of()
+}
+
+/// Gets the alignment of the value behind a trait object, from its vtable.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::align_of_val_dyn;
+/// 
+/// trait PlayerObj {}
+/// 
+/// struct Human
+/// {
+///     hp: u64
+/// }
+/// 
+/// impl PlayerObj for Human {}
+/// 
+/// assert_eq!(align_of_val_dyn(&Human {hp: 10} as &dyn PlayerObj), core::mem::align_of::<u64>());
+/// ```
+pub fn align_of_val_dyn<Trait>(obj: &Trait) -> usize
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    vtable_of(obj).align_of()
+}
+
+/// Rebuilds a reference to a trait object from a data pointer and its vtable.
+/// 
+/// # Safety
+/// The data pointer must point to a valid value of the type the vtable was made for, which must stay valid and unmutated for the lifetime `'a`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{from_raw_parts, vtable_of};
+/// 
+/// trait PlayerObj
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// 
+/// struct Human;
+/// 
+/// impl PlayerObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "human"
+///     }
+/// }
+/// 
+/// let human = Human;
+/// let player = &human as &dyn PlayerObj;
+/// 
+/// let data = player as *const dyn PlayerObj as *const ();
+/// let vtable = vtable_of(player);
+/// 
+/// // SAFETY: The data pointer points to `human`, which the vtable was made for.
+/// let player = unsafe {from_raw_parts(data, vtable)};
+/// assert_eq!(player.name(), "human");
+/// ```
+pub unsafe fn from_raw_parts<'a, Trait>(data: *const (), vtable: DynMetadata<Trait>) -> &'a Trait
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    // SAFETY: Upheld by the caller.
+    unsafe {&*ptr::from_raw_parts(data, vtable)}
+}
+
+/// Rebuilds a mutable reference to a trait object from a data pointer and its vtable.
+/// 
+/// # Safety
+/// The data pointer must point to a valid value of the type the vtable was made for, which must stay valid and unaliased for the lifetime `'a`.
+pub unsafe fn from_raw_parts_mut<'a, Trait>(data: *mut (), vtable: DynMetadata<Trait>) -> &'a mut Trait
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    // SAFETY: Upheld by the caller.
+    unsafe {&mut *ptr::from_raw_parts_mut(data, vtable)}
+}
@@ -1,7 +1,12 @@
-use std::any::Any;
-
 use super::*;
 
+/// A trait which signifies that Self can be cast into the trait-object `Trait` and downcast back from it.
+/// 
+/// This is implemented for every type that is, and can be upcast into and downcast from, the trait-object.
+/// 
+/// # Safety
+/// 
+/// Self must be an object of `Trait`, as given by [Is](Is), which is upheld by the blanket impl.
 pub unsafe trait Object<Trait>
 where
     Trait: ?Sized
@@ -19,9 +24,58 @@ where
 
 }
 
+/// Implements the casts of a trait object into itself, making it an object that can be downcast and converted.
+/// 
+/// Standard traits can also be implemented for the trait object by listing them after `with`,
+/// when the object trait extends the matching helper trait:
+/// - `PartialEq`, by [DynEq](crate::DynEq).
+/// - `Eq`, by [DynTotalEq](crate::DynTotalEq).
+/// - `Hash`, by [DynHash](crate::DynHash).
+/// - `PartialOrd` and `Ord`, by [DynOrd](crate::DynOrd).
+/// - `Clone`, for the boxed trait object, by [DynClone](crate::DynClone).
+/// 
+/// For a struct, the traits it implements can be listed instead, filling in its table of [DynImplements](crate::DynImplements).
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{DynEq, impl_object};
+/// use core::any::Any;
+/// 
+/// trait ShapeObj: Any + DynEq {}
+/// impl_object!(ShapeObj with PartialEq);
+/// 
+/// #[derive(PartialEq)]
+/// struct Circle(u32);
+/// 
+/// impl ShapeObj for Circle {}
+/// 
+/// let a = Box::new(Circle(1)) as Box<dyn ShapeObj>;
+/// let b = Box::new(Circle(1)) as Box<dyn ShapeObj>;
+/// 
+/// assert!(a == b);
+/// ```
+/// 
+/// ```rust
+/// use spellcast::{DynImplements, Implements, impl_object};
+/// use core::fmt::Debug;
+/// 
+/// trait PlayerObj: DynImplements {}
+/// impl_object!(PlayerObj);
+/// 
+/// #[derive(Debug)]
+/// struct Human;
+/// 
+/// impl PlayerObj for Human {}
+/// impl_object!(Human: dyn Debug, dyn PlayerObj);
+/// 
+/// let player = Box::new(Human) as Box<dyn PlayerObj>;
+/// 
+/// assert!(player.implements::<dyn Debug>());
+/// ```
 #[macro_export]
 macro_rules! impl_object {
-    ($trait:ident $(<$($generics:tt),*>)? $(where $($whre:tt)*)?) => {
+    ($trait:ident $(<$($generics:tt),*>)? $(with $($with:ident),+)? $(where $($whre:tt)*)?) => {
+        spellcast::__impl_object_with!($trait [$(<$($generics),*>)?] [$(where $($whre)*)?] $($($with),+)?);
         impl $(<$($generics),*>)? spellcast::downcast::DowncastFromRef<dyn $trait $(<$($generics),*>)?> for dyn $trait $(<$($generics),*>)?
         $(where $($whre)*)?
         {
@@ -38,7 +92,19 @@ macro_rules! impl_object {
         impl $(<$($generics),*>)? spellcast::downcast::DowncastFrom<dyn $trait $(<$($generics),*>)?, dyn $trait $(<$($generics),*>)?> for dyn $trait $(<$($generics),*>)?
         $(where $($whre)*)?
         {
-            fn downcast_from(from: Box<dyn $trait $(<$($generics),*>)?>) -> Result<Box<Self>, Box<dyn $trait $(<$($generics),*>)?>>
+            fn downcast_from(from: spellcast::alloc::boxed::Box<dyn $trait $(<$($generics),*>)?>) -> Result<spellcast::alloc::boxed::Box<Self>, spellcast::alloc::boxed::Box<dyn $trait $(<$($generics),*>)?>>
+            {
+                Ok(from)
+            }
+            fn downcast_from_pin(from: core::pin::Pin<spellcast::alloc::boxed::Box<dyn $trait $(<$($generics),*>)?>>) -> Result<core::pin::Pin<spellcast::alloc::boxed::Box<Self>>, core::pin::Pin<spellcast::alloc::boxed::Box<dyn $trait $(<$($generics),*>)?>>>
+            {
+                Ok(from)
+            }
+            fn downcast_from_rc(from: spellcast::alloc::rc::Rc<dyn $trait $(<$($generics),*>)?>) -> Result<spellcast::alloc::rc::Rc<Self>, spellcast::alloc::rc::Rc<dyn $trait $(<$($generics),*>)?>>
+            {
+                Ok(from)
+            }
+            fn downcast_from_arc(from: spellcast::alloc::sync::Arc<dyn $trait $(<$($generics),*>)?>) -> Result<spellcast::alloc::sync::Arc<Self>, spellcast::alloc::sync::Arc<dyn $trait $(<$($generics),*>)?>>
             {
                 Ok(from)
             }
@@ -46,27 +112,165 @@ macro_rules! impl_object {
         impl $(<$($generics),*>)? spellcast::convert::TryConvertInto<dyn $trait $(<$($generics),*>)?, dyn $trait $(<$($generics),*>)?> for dyn $trait $(<$($generics),*>)?
         $(where $($whre)*)?
         {
-            fn try_convert_into(self: Box<Self>) -> Result<Box<dyn $trait $(<$($generics),*>)?>, Box<dyn $trait $(<$($generics),*>)?>>
+            fn try_convert_into(self: spellcast::alloc::boxed::Box<Self>) -> Result<spellcast::alloc::boxed::Box<dyn $trait $(<$($generics),*>)?>, spellcast::alloc::boxed::Box<dyn $trait $(<$($generics),*>)?>>
             {
                 Ok(self)
             }
         }
+        spellcast::__impl_object_stable!($trait $(<$($generics),*>)? $(where $($whre)*)?);
         //spellcast::assert_is!(dyn $trait: $trait);
     };
+    ($struct:ty : $($to:ty),* $(,)?) => {
+        unsafe impl spellcast::DynImplements for $struct
+        {
+            #[allow(unused_variables)]
+            fn __as_trait_ptr(self: &Self, to: &mut dyn core::any::Any)
+            {
+                $(
+                    if let Some(to) = to.downcast_mut::<Option<*const $to>>()
+                    {
+                        *to = Some(self as &$to as *const $to);
+                        return
+                    }
+                )*
+            }
+            #[allow(unused_variables)]
+            fn __as_trait_ptr_mut(self: &mut Self, to: &mut dyn core::any::Any)
+            {
+                $(
+                    if let Some(to) = to.downcast_mut::<Option<*mut $to>>()
+                    {
+                        *to = Some(self as &mut $to as *mut $to);
+                        return
+                    }
+                )*
+            }
+        }
+    };
+}
+
+#[cfg(feature = "nightly")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_object_stable {
+    ($($tt:tt)*) => {};
+}
+
+/// Without the `nightly` feature, trait objects can't be unsized generically, so [Is](crate::Is) and [AsAny](crate::AsAny) are implemented here,
+/// also for the `Send` and `Send + Sync` variants of the trait object, so that they keep their auto traits when given back.
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_object_stable {
+    ($trait:ident $(<$($generics:tt),*>)? $(where $($whre:tt)*)?) => {
+        spellcast::__impl_object_stable!(@variant [] $trait $(<$($generics),*>)? $(where $($whre)*)?);
+        spellcast::__impl_object_stable!(@variant [+ Send] $trait $(<$($generics),*>)? $(where $($whre)*)?);
+        spellcast::__impl_object_stable!(@variant [+ Send + Sync] $trait $(<$($generics),*>)? $(where $($whre)*)?);
+    };
+    (@variant [$($auto:tt)*] $trait:ident $(<$($generics:tt),*>)? $(where $($whre:tt)*)?) => {
+        unsafe impl $(<$($generics),*>)? spellcast::Is<dyn $trait $(<$($generics),*>)? $($auto)*> for dyn $trait $(<$($generics),*>)? $($auto)*
+        $(where $($whre)*)?
+        {
+            fn as_obj_ptr(from: *const Self) -> *const (dyn $trait $(<$($generics),*>)? $($auto)* + 'static)
+            {
+                from
+            }
+            fn as_obj_ptr_mut(from: *mut Self) -> *mut (dyn $trait $(<$($generics),*>)? $($auto)* + 'static)
+            {
+                from
+            }
+        }
+        impl $(<$($generics),*>)? spellcast::AsAny for dyn $trait $(<$($generics),*>)? $($auto)*
+        $(where $($whre)*)?
+        {
+            fn as_any<'a>(self: &'a Self) -> &'a dyn core::any::Any
+            {
+                self
+            }
+            fn as_any_mut<'a>(self: &'a mut Self) -> &'a mut dyn core::any::Any
+            {
+                self
+            }
+            fn into_any(self: spellcast::alloc::boxed::Box<Self>) -> spellcast::alloc::boxed::Box<dyn core::any::Any>
+            {
+                self
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_object_with {
+    ($trait:ident [$($generics:tt)*] [$($whre:tt)*]) => {};
+    ($trait:ident [$($generics:tt)*] [$($whre:tt)*] PartialEq $(, $($rest:ident),+)?) => {
+        impl $($generics)* PartialEq for dyn $trait $($generics)*
+        $($whre)*
+        {
+            fn eq(&self, other: &Self) -> bool
+            {
+                spellcast::DynEq::dyn_eq(self, spellcast::AsAny::as_any(other))
+            }
+        }
+        spellcast::__impl_object_with!($trait [$($generics)*] [$($whre)*] $($($rest),+)?);
+    };
+    ($trait:ident [$($generics:tt)*] [] Eq $(, $($rest:ident),+)?) => {
+        impl $($generics)* Eq for dyn $trait $($generics)*
+        where dyn $trait $($generics)*: spellcast::DynTotalEq {}
+        spellcast::__impl_object_with!($trait [$($generics)*] [] $($($rest),+)?);
+    };
+    ($trait:ident [$($generics:tt)*] [where $($whre:tt)*] Eq $(, $($rest:ident),+)?) => {
+        impl $($generics)* Eq for dyn $trait $($generics)*
+        where dyn $trait $($generics)*: spellcast::DynTotalEq, $($whre)* {}
+        spellcast::__impl_object_with!($trait [$($generics)*] [where $($whre)*] $($($rest),+)?);
+    };
+    ($trait:ident [$($generics:tt)*] [$($whre:tt)*] Hash $(, $($rest:ident),+)?) => {
+        impl $($generics)* core::hash::Hash for dyn $trait $($generics)*
+        $($whre)*
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H)
+            {
+                spellcast::DynHash::dyn_hash(self, state)
+            }
+        }
+        spellcast::__impl_object_with!($trait [$($generics)*] [$($whre)*] $($($rest),+)?);
+    };
+    ($trait:ident [$($generics:tt)*] [$($whre:tt)*] PartialOrd $(, $($rest:ident),+)?) => {
+        impl $($generics)* PartialOrd for dyn $trait $($generics)*
+        $($whre)*
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering>
+            {
+                Some(spellcast::DynOrd::dyn_cmp(self, spellcast::AsAny::as_any(other)))
+            }
+        }
+        spellcast::__impl_object_with!($trait [$($generics)*] [$($whre)*] $($($rest),+)?);
+    };
+    ($trait:ident [$($generics:tt)*] [$($whre:tt)*] Ord $(, $($rest:ident),+)?) => {
+        impl $($generics)* Ord for dyn $trait $($generics)*
+        $($whre)*
+        {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering
+            {
+                spellcast::DynOrd::dyn_cmp(self, spellcast::AsAny::as_any(other))
+            }
+        }
+        spellcast::__impl_object_with!($trait [$($generics)*] [$($whre)*] $($($rest),+)?);
+    };
+    ($trait:ident [$($generics:tt)*] [$($whre:tt)*] Clone $(, $($rest:ident),+)?) => {
+        spellcast::__impl_object_with!(@clone [] $trait [$($generics)*] [$($whre)*]);
+        spellcast::__impl_object_with!(@clone [+ Send] $trait [$($generics)*] [$($whre)*]);
+        spellcast::__impl_object_with!(@clone [+ Send + Sync] $trait [$($generics)*] [$($whre)*]);
+        spellcast::__impl_object_with!($trait [$($generics)*] [$($whre)*] $($($rest),+)?);
+    };
+    (@clone [$($auto:tt)*] $trait:ident [$($generics:tt)*] [$($whre:tt)*]) => {
+        impl $($generics)* Clone for spellcast::alloc::boxed::Box<dyn $trait $($generics)* $($auto)*>
+        $($whre)*
+        {
+            fn clone(&self) -> Self
+            {
+                spellcast::clone_box(&**self)
+            }
+        }
+    };
 }
-pub macro assert_is {
-    ($type:ty : $trait:path) => {
-        static_assertions::assert_obj_safe!(crate::Object<dyn $trait>);
-        static_assertions::assert_impl_one!(dyn core::any::Any: core::marker::Unsize<dyn core::any::Any>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::Is<dyn $trait>);
-        static_assertions::assert_impl_one!($type: core::marker::Unsize<dyn $trait>);
-        static_assertions::assert_impl_one!($type: crate::Is<dyn $trait>);
-        static_assertions::assert_impl_one!($type: crate::upcast::Upcast<dyn $trait>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::DowncastFrom<dyn $trait, dyn $trait>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::DowncastFromRef<dyn $trait>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::DowncastRef<dyn $trait>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::Downcast<dyn $trait, dyn $trait>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::downcast::Downcast<$type, dyn $trait>);
-        static_assertions::assert_impl_one!(dyn $trait: crate::Object<dyn $trait>);
-    }
-}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::any::Any;
+
+use crate::upcast::Upcast;
+
+/// A type-erased cast from some concrete struct into the trait object `To`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{registry::Caster, impl_is};
+/// use core::any::Any;
+/// 
+/// trait Renderable {}
+/// 
+/// struct Sprite;
+/// 
+/// impl Renderable for Sprite {}
+/// 
+/// impl_is!(Sprite: dyn Renderable);
+/// 
+/// let caster = Caster::<dyn Renderable>::of::<Sprite>();
+/// 
+/// let any = Box::new(Sprite) as Box<dyn Any>;
+/// 
+/// assert!(caster.cast_ref(&*any).is_some());
+/// assert!(caster.cast_ref(&0u8).is_none());
+/// ```
+pub struct Caster<To>
+where
+    To: ?Sized + 'static
+{
+    cast_ref: fn(&dyn Any) -> Option<&To>,
+    cast_mut: fn(&mut dyn Any) -> Option<&mut To>,
+    cast_box: CastBox<To>
+}
+
+/// A cast of a boxed object, giving it back on failure.
+type CastBox<To> = fn(Box<dyn Any>) -> Result<Box<To>, Box<dyn Any>>;
+
+impl<To> Caster<To>
+where
+    To: ?Sized + 'static
+{
+    /// Creates a cast from the struct `Struct` into the trait object `To`.
+    pub fn of<Struct>() -> Self
+    where
+        Struct: Upcast<To> + 'static
+    {
+        Self {
+            cast_ref: |from| from.downcast_ref::<Struct>().map(|vee| vee.upcast_ref()),
+            cast_mut: |from| from.downcast_mut::<Struct>().map(|vee| vee.upcast_mut()),
+            cast_box: |from| from.downcast::<Struct>().map(|vee| vee.upcast())
+        }
+    }
+
+    /// Casts a reference into the trait object `To`, if it is of the struct the caster was made for.
+    pub fn cast_ref<'a>(&self, from: &'a dyn Any) -> Option<&'a To>
+    {
+        (self.cast_ref)(from)
+    }
+
+    /// Casts a mutable reference into the trait object `To`, if it is of the struct the caster was made for.
+    pub fn cast_mut<'a>(&self, from: &'a mut dyn Any) -> Option<&'a mut To>
+    {
+        (self.cast_mut)(from)
+    }
+
+    /// Casts a box into the trait object `To`, if it is of the struct the caster was made for.
+    /// 
+    /// On failure, the original box is given back.
+    pub fn cast_box(&self, from: Box<dyn Any>) -> Result<Box<To>, Box<dyn Any>>
+    {
+        (self.cast_box)(from)
+    }
+}
+
+impl<To> Clone for Caster<To>
+where
+    To: ?Sized + 'static
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+impl<To> Copy for Caster<To>
+where
+    To: ?Sized + 'static {}
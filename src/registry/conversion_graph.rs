@@ -0,0 +1,310 @@
+use std::{any::{type_name, Any, TypeId}, cmp::Reverse, collections::{BTreeMap, BinaryHeap}, sync::{PoisonError, RwLock}};
+
+use crate::{convert::ConvertInto, AsAny, CastError};
+
+/// A conversion of a boxed object from one concrete type into another, as an edge of a [ConversionGraph](ConversionGraph).
+pub type Converter = fn(Box<dyn Any>) -> Box<dyn Any>;
+
+#[derive(Clone, Copy)]
+struct Edge
+{
+    weight: u64,
+    convert: Converter
+}
+
+/// A graph of conversions between concrete types, which can chain several conversions to reach a target type.
+/// 
+/// The cheapest path is used, where each conversion costs its weight, and found paths are cached until the graph changes.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{registry::ConversionGraph, convert::ConvertInto, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// 
+/// struct Human;
+/// struct Bot;
+/// struct Npc;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl PlayerObj for Npc {}
+/// 
+/// impl ConvertInto<Npc> for Bot
+/// {
+///     fn convert_into(self: Box<Self>) -> Box<Npc>
+///     {
+///         Box::new(Npc)
+///     }
+/// }
+/// impl ConvertInto<Human> for Npc
+/// {
+///     fn convert_into(self: Box<Self>) -> Box<Human>
+///     {
+///         Box::new(Human)
+///     }
+/// }
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// impl_is!(Npc: dyn PlayerObj);
+/// 
+/// let graph = ConversionGraph::new();
+/// graph.register::<Bot, Npc>();
+/// graph.register::<Npc, Human>();
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// let human: Box<Human> = graph.convert_to::<Human, _>(player).ok().unwrap();
+/// 
+/// let human = human as Box<dyn PlayerObj>;
+/// assert!(graph.convert_to::<Bot, _>(human).is_err());
+/// ```
+pub struct ConversionGraph
+{
+    edges: RwLock<BTreeMap<TypeId, BTreeMap<TypeId, Edge>>>,
+    paths: RwLock<BTreeMap<(TypeId, TypeId), CachedPath>>
+}
+
+/// A found path, as the conversions along with the type each converts into, or the absence of one.
+type CachedPath = Option<Vec<(TypeId, Converter)>>;
+
+static GLOBAL: ConversionGraph = ConversionGraph::new();
+
+fn convert_edge<From, To>(from: Box<dyn Any>) -> Box<dyn Any>
+where
+    From: ConvertInto<To> + 'static,
+    To: 'static
+{
+    from.downcast::<From>()
+        .unwrap_or_else(|_| panic!("converter given an object of another type than `{}`", type_name::<From>()))
+        .convert_into()
+}
+
+impl ConversionGraph
+{
+    /// Creates an empty conversion graph.
+    pub const fn new() -> Self
+    {
+        Self {
+            edges: RwLock::new(BTreeMap::new()),
+            paths: RwLock::new(BTreeMap::new())
+        }
+    }
+
+    /// The global conversion graph, used by the conversion functions of the [registry](crate::registry) module.
+    pub fn global() -> &'static Self
+    {
+        &GLOBAL
+    }
+
+    /// Adds a conversion from the concrete type with [TypeId](TypeId) `from` into the one with `to`, with a given weight.
+    /// 
+    /// The converter is only ever given objects of the type `from`, and must return an object of the type `to`,
+    /// which is checked whenever it is used by [convert_to](ConversionGraph::convert_to).
+    /// Adding the same pair twice replaces the previous conversion.
+    pub fn add_edge(&self, from: TypeId, to: TypeId, weight: u64, convert: Converter)
+    {
+        self.edges.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(from)
+            .or_default()
+            .insert(to, Edge {weight, convert});
+        self.paths.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Registers the conversion of `From` into `To`, given by [ConvertInto](ConvertInto), with a weight of 1.
+    pub fn register<From, To>(&self)
+    where
+        From: ConvertInto<To> + 'static,
+        To: 'static
+    {
+        self.register_weighted::<From, To>(1)
+    }
+
+    /// Registers the conversion of `From` into `To`, given by [ConvertInto](ConvertInto), with a given weight.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{registry::ConversionGraph, convert::ConvertInto};
+    /// 
+    /// #[derive(PartialEq, Debug)]
+    /// struct Celsius(f64);
+    /// #[derive(PartialEq, Debug)]
+    /// struct Fahrenheit(f64);
+    /// #[derive(PartialEq, Debug)]
+    /// struct Kelvin(f64);
+    /// 
+    /// impl ConvertInto<Kelvin> for Celsius
+    /// {
+    ///     fn convert_into(self: Box<Self>) -> Box<Kelvin>
+    ///     {
+    ///         Box::new(Kelvin(self.0 + 273.0))
+    ///     }
+    /// }
+    /// impl ConvertInto<Fahrenheit> for Celsius
+    /// {
+    ///     fn convert_into(self: Box<Self>) -> Box<Fahrenheit>
+    ///     {
+    ///         Box::new(Fahrenheit(self.0 * 1.8 + 32.0))
+    ///     }
+    /// }
+    /// impl ConvertInto<Kelvin> for Fahrenheit
+    /// {
+    ///     fn convert_into(self: Box<Self>) -> Box<Kelvin>
+    ///     {
+    ///         Box::new(Kelvin((self.0 - 32.0) / 1.8 + 273.0))
+    ///     }
+    /// }
+    /// 
+    /// let graph = ConversionGraph::new();
+    /// graph.register_weighted::<Celsius, Kelvin>(10);
+    /// graph.register_weighted::<Celsius, Fahrenheit>(1);
+    /// graph.register_weighted::<Fahrenheit, Kelvin>(1);
+    /// 
+    /// // The two cheap conversions are preferred over the single expensive one.
+    /// assert_eq!(graph.path_len::<Celsius, Kelvin>(), Some(2));
+    /// assert_eq!(*graph.convert_to::<Kelvin, _>(Box::new(Celsius(0.0))).ok().unwrap(), Kelvin(273.0));
+    /// ```
+    pub fn register_weighted<From, To>(&self, weight: u64)
+    where
+        From: ConvertInto<To> + 'static,
+        To: 'static
+    {
+        self.add_edge(TypeId::of::<From>(), TypeId::of::<To>(), weight, convert_edge::<From, To>)
+    }
+
+    /// Finds the cheapest chain of conversions from the concrete type with [TypeId](TypeId) `from` into the one with `to`.
+    /// 
+    /// The path is empty if the types are the same.
+    /// 
+    /// The converters registered with [register](ConversionGraph::register) panic if given an object of another type than they convert from.
+    pub fn path(&self, from: TypeId, to: TypeId) -> Option<Vec<Converter>>
+    {
+        self.cached_path(from, to)
+            .map(|path| path.into_iter()
+                .map(|(_, convert)| convert)
+                .collect()
+            )
+    }
+
+    fn cached_path(&self, from: TypeId, to: TypeId) -> CachedPath
+    {
+        if let Some(path) = self.paths.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(from, to))
+        {
+            return path.clone()
+        }
+
+        // The path is found while holding the cache, so that an edge added meanwhile clears it only after the path is cached.
+        self.paths.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((from, to))
+            .or_insert_with(|| self.find_path(from, to))
+            .clone()
+    }
+
+    /// Returns the number of conversions in the cheapest chain from `From` into `To`, if any.
+    pub fn path_len<From, To>(&self) -> Option<usize>
+    where
+        From: ?Sized + 'static,
+        To: ?Sized + 'static
+    {
+        self.path(TypeId::of::<From>(), TypeId::of::<To>())
+            .map(|path| path.len())
+    }
+
+    fn find_path(&self, from: TypeId, to: TypeId) -> CachedPath
+    {
+        let edges = self.edges.read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // Dijkstra's algorithm, remembering the edge each type was reached by.
+        let mut reached: BTreeMap<TypeId, (u64, Option<(TypeId, Converter)>)> = BTreeMap::new();
+        let mut queue = BinaryHeap::new();
+        reached.insert(from, (0, None));
+        queue.push(Reverse((0, from)));
+
+        while let Some(Reverse((cost, node))) = queue.pop()
+        {
+            if node == to
+            {
+                break
+            }
+            if reached.get(&node).is_some_and(|&(best, _)| best < cost)
+            {
+                continue
+            }
+            for (&next, edge) in edges.get(&node).into_iter().flatten()
+            {
+                let next_cost = cost.saturating_add(edge.weight);
+                if reached.get(&next).is_none_or(|&(best, _)| next_cost < best)
+                {
+                    reached.insert(next, (next_cost, Some((node, edge.convert))));
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut node = to;
+        while let (_, Some((prev, convert))) = reached.get(&node)?
+        {
+            path.push((node, *convert));
+            node = *prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Converts a boxed object into `To`, by the cheapest chain of registered conversions from its concrete type.
+    /// 
+    /// If there is no such chain, the original object is given back.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if a converter added with [add_edge](ConversionGraph::add_edge) returns an object of another type than its edge converts into.
+    /// 
+    /// ```rust,should_panic
+    /// use spellcast::registry::ConversionGraph;
+    /// use core::any::TypeId;
+    /// 
+    /// let graph = ConversionGraph::new();
+    /// // Claims to convert a `u8` into a `u16`, but returns a `u32`.
+    /// graph.add_edge(TypeId::of::<u8>(), TypeId::of::<u16>(), 1, |from| Box::new(*from.downcast::<u8>().unwrap() as u32));
+    /// 
+    /// let _ = graph.convert_to::<u16, _>(Box::new(1u8));
+    /// ```
+    pub fn convert_to<To, From>(&self, from: Box<From>) -> Result<Box<To>, CastError<Box<From>>>
+    where
+        To: 'static,
+        From: AsAny + ?Sized
+    {
+        let type_id = (*from).as_any().type_id();
+        let Some(path) = self.cached_path(type_id, TypeId::of::<To>())
+        else
+        {
+            return Err(CastError::new::<From, To>(from, type_id))
+        };
+        let object = path.into_iter()
+            .fold(from.into_any(), |object, (to, convert)| {
+                let object = convert(object);
+                assert!((*object).type_id() == to, "converter returned an object of another type than its edge converts into");
+                object
+            });
+        // The last conversion is checked to be into `To`, or there are none and the object already is a `To`.
+        Ok(object.downcast().unwrap_or_else(|_| panic!("converted object is not a `{}`", type_name::<To>())))
+    }
+}
+
+impl Default for ConversionGraph
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
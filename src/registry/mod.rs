@@ -0,0 +1,190 @@
+moddef::moddef!(
+    flat(pub) mod {
+        caster,
+        registry_,
+        conversion_graph
+    }
+);
+
+use crate::{convert::ConvertInto, upcast::Upcast, CastError};
+
+use super::*;
+
+/// Registers casts from a struct into one or more trait objects in the global [Registry](Registry).
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{registry, register_cast, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait Component: Any {}
+/// impl_object!(Component);
+/// trait Renderable
+/// {
+///     fn render(&self) -> String;
+/// }
+/// 
+/// struct Sprite;
+/// 
+/// impl Component for Sprite {}
+/// impl Renderable for Sprite
+/// {
+///     fn render(&self) -> String
+///     {
+///         "sprite".to_string()
+///     }
+/// }
+/// 
+/// impl_is!(Sprite: dyn Component, dyn Renderable);
+/// 
+/// register_cast!(Sprite: dyn Renderable, dyn Component);
+/// 
+/// let component = Box::new(Sprite) as Box<dyn Component>;
+/// 
+/// let renderable = registry::cast_ref::<dyn Renderable, _>(&*component).unwrap();
+/// assert_eq!(renderable.render(), "sprite");
+/// ```
+#[macro_export]
+macro_rules! register_cast {
+    ($struct:ty : $($to:ty),+ $(,)?) => {
+        $(
+            spellcast::registry::register::<$struct, $to>();
+        )+
+    };
+}
+
+/// Registers a cast from a struct into a trait object in the global [Registry](Registry).
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{registry, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait Component: Any {}
+/// impl_object!(Component);
+/// trait Renderable {}
+/// 
+/// struct Sprite;
+/// 
+/// impl Component for Sprite {}
+/// impl Renderable for Sprite {}
+/// 
+/// registry::register::<Sprite, dyn Renderable>();
+/// 
+/// impl_is!(Sprite: dyn Component, dyn Renderable);
+/// 
+/// let component = Box::new(Sprite) as Box<dyn Component>;
+/// 
+/// assert!(registry::implements::<dyn Renderable, _>(&*component));
+/// ```
+pub fn register<Struct, To>()
+where
+    Struct: Upcast<To> + 'static,
+    To: ?Sized + 'static
+{
+    Registry::global().register::<Struct, To>()
+}
+
+/// Checks if the concrete type of an object has a registered cast into the trait object `To` in the global [Registry](Registry).
+pub fn implements<To, From>(from: &From) -> bool
+where
+    To: ?Sized + 'static,
+    From: AsAny + ?Sized
+{
+    Registry::global().implements::<To, From>(from)
+}
+
+/// Casts a reference to an object into another trait object, using the cast registered in the global [Registry](Registry) for its concrete type.
+pub fn cast_ref<To, From>(from: &From) -> Option<&To>
+where
+    To: ?Sized + 'static,
+    From: AsAny + ?Sized
+{
+    Registry::global().cast_ref(from)
+}
+
+/// Casts a mutable reference to an object into another trait object, using the cast registered in the global [Registry](Registry) for its concrete type.
+pub fn cast_mut<To, From>(from: &mut From) -> Option<&mut To>
+where
+    To: ?Sized + 'static,
+    From: AsAny + ?Sized
+{
+    Registry::global().cast_mut(from)
+}
+
+/// Casts a boxed object into another trait object, using the cast registered in the global [Registry](Registry) for its concrete type.
+/// 
+/// On failure, the original object is given back.
+pub fn cast_box<To, From>(from: Box<From>) -> Result<Box<To>, Box<From>>
+where
+    To: ?Sized + 'static,
+    From: AsAny + ?Sized
+{
+    Registry::global().cast_box(from)
+}
+
+/// Registers the conversion of `From` into `To`, given by [ConvertInto](crate::convert::ConvertInto), in the global [ConversionGraph](ConversionGraph).
+pub fn register_conversion<From, To>()
+where
+    From: ConvertInto<To> + 'static,
+    To: 'static
+{
+    ConversionGraph::global().register::<From, To>()
+}
+
+/// Converts a boxed object into `To`, by the cheapest chain of conversions from its concrete type in the global [ConversionGraph](ConversionGraph).
+/// 
+/// If there is no such chain, the original object is given back.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{registry, convert::ConvertInto, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// 
+/// struct Human;
+/// struct Bot;
+/// struct Npc;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl PlayerObj for Npc {}
+/// 
+/// impl ConvertInto<Npc> for Bot
+/// {
+///     fn convert_into(self: Box<Self>) -> Box<Npc>
+///     {
+///         Box::new(Npc)
+///     }
+/// }
+/// impl ConvertInto<Human> for Npc
+/// {
+///     fn convert_into(self: Box<Self>) -> Box<Human>
+///     {
+///         Box::new(Human)
+///     }
+/// }
+/// 
+/// registry::register_conversion::<Bot, Npc>();
+/// registry::register_conversion::<Npc, Human>();
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// impl_is!(Bot: dyn PlayerObj);
+/// impl_is!(Npc: dyn PlayerObj);
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// assert!(registry::convert_to::<Human, _>(player).is_ok());
+/// 
+/// let player = Box::new(Human) as Box<dyn PlayerObj>;
+/// let error = registry::convert_to::<Npc, _>(player).err().unwrap();
+/// assert_eq!(error.target_type_name(), core::any::type_name::<Npc>());
+/// ```
+pub fn convert_to<To, From>(from: Box<From>) -> Result<Box<To>, CastError<Box<From>>>
+where
+    To: 'static,
+    From: AsAny + ?Sized
+{
+    ConversionGraph::global().convert_to(from)
+}
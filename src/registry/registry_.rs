@@ -0,0 +1,152 @@
+use std::{any::{Any, TypeId}, collections::BTreeMap, sync::{PoisonError, RwLock}};
+
+use crate::upcast::Upcast;
+
+use super::*;
+
+/// A registry of casts between trait objects, keyed on the concrete type of the object and the trait object to cast into.
+/// 
+/// This allows casting from one trait object to another without knowing the concrete type of the object,
+/// as long as a cast has been registered for it.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{registry::Registry, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait Component: Any {}
+/// impl_object!(Component);
+/// trait Renderable
+/// {
+///     fn render(&self) -> String;
+/// }
+/// 
+/// struct Sprite;
+/// struct Collider;
+/// 
+/// impl Component for Sprite {}
+/// impl Renderable for Sprite
+/// {
+///     fn render(&self) -> String
+///     {
+///         "sprite".to_string()
+///     }
+/// }
+/// impl Component for Collider {}
+/// 
+/// impl_is!(Sprite: dyn Component, dyn Renderable);
+/// impl_is!(Collider: dyn Component);
+/// 
+/// let registry = Registry::new();
+/// registry.register::<Sprite, dyn Renderable>();
+/// 
+/// let sprite = Box::new(Sprite) as Box<dyn Component>;
+/// let collider = Box::new(Collider) as Box<dyn Component>;
+/// 
+/// assert_eq!(registry.cast_ref::<dyn Renderable, _>(&*sprite).map(|r| r.render()), Some("sprite".to_string()));
+/// assert!(registry.cast_ref::<dyn Renderable, _>(&*collider).is_none());
+/// 
+/// assert!(registry.cast_box::<dyn Renderable, _>(collider).is_err());
+/// assert!(registry.cast_box::<dyn Renderable, _>(sprite).is_ok());
+/// ```
+pub struct Registry
+{
+    casters: RwLock<BTreeMap<(TypeId, TypeId), Box<dyn Any + Send + Sync>>>
+}
+
+static GLOBAL: Registry = Registry::new();
+
+impl Registry
+{
+    /// Creates an empty registry.
+    pub const fn new() -> Self
+    {
+        Self {
+            casters: RwLock::new(BTreeMap::new())
+        }
+    }
+
+    /// The global registry, used by [register_cast](crate::register_cast) and the free functions of the [registry](crate::registry) module.
+    pub fn global() -> &'static Self
+    {
+        &GLOBAL
+    }
+
+    /// Registers a cast from the struct `Struct` into the trait object `To`.
+    /// 
+    /// Registering the same pair twice has no further effect.
+    pub fn register<Struct, To>(&self)
+    where
+        Struct: Upcast<To> + 'static,
+        To: ?Sized + 'static
+    {
+        self.casters.write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((TypeId::of::<Struct>(), TypeId::of::<To>()))
+            .or_insert_with(|| Box::new(Caster::<To>::of::<Struct>()));
+    }
+
+    /// Looks up the registered cast from the concrete type with the given [TypeId](TypeId) into the trait object `To`.
+    pub fn caster<To>(&self, type_id: TypeId) -> Option<Caster<To>>
+    where
+        To: ?Sized + 'static
+    {
+        self.casters.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(type_id, TypeId::of::<To>()))
+            .and_then(|caster| caster.downcast_ref::<Caster<To>>())
+            .copied()
+    }
+
+    /// Checks if the concrete type of an object has a registered cast into the trait object `To`.
+    pub fn implements<To, From>(&self, from: &From) -> bool
+    where
+        To: ?Sized + 'static,
+        From: AsAny + ?Sized
+    {
+        self.caster::<To>(from.as_any().type_id()).is_some()
+    }
+
+    /// Casts a reference to an object into another trait object, using the cast registered for its concrete type.
+    pub fn cast_ref<'a, To, From>(&self, from: &'a From) -> Option<&'a To>
+    where
+        To: ?Sized + 'static,
+        From: AsAny + ?Sized
+    {
+        let from = from.as_any();
+        self.caster::<To>((*from).type_id())?.cast_ref(from)
+    }
+
+    /// Casts a mutable reference to an object into another trait object, using the cast registered for its concrete type.
+    pub fn cast_mut<'a, To, From>(&self, from: &'a mut From) -> Option<&'a mut To>
+    where
+        To: ?Sized + 'static,
+        From: AsAny + ?Sized
+    {
+        let from = from.as_any_mut();
+        self.caster::<To>((*from).type_id())?.cast_mut(from)
+    }
+
+    /// Casts a boxed object into another trait object, using the cast registered for its concrete type.
+    /// 
+    /// On failure, the original object is given back.
+    pub fn cast_box<To, From>(&self, from: Box<From>) -> Result<Box<To>, Box<From>>
+    where
+        To: ?Sized + 'static,
+        From: AsAny + ?Sized
+    {
+        match self.caster::<To>((*from).as_any().type_id())
+        {
+            Some(caster) => Ok(caster.cast_box(from.into_any()).unwrap_or_else(|_| unreachable!())),
+            None => Err(from)
+        }
+    }
+}
+
+impl Default for Registry
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
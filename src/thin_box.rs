@@ -0,0 +1,307 @@
+use core::{alloc::Layout, any::TypeId, fmt, marker::PhantomData, mem, ops::{Deref, DerefMut}, ptr::{self, DynMetadata, NonNull, Pointee}};
+
+use alloc::{alloc::{alloc, dealloc, handle_alloc_error}, boxed::Box};
+
+use super::*;
+
+/// The header in front of the object in the allocation of a [ThinBox](ThinBox).
+/// 
+/// Its layout doesn't depend on `Trait`, so the vtable can be rewritten in place when casting.
+#[repr(C)]
+struct Header<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    vtable: DynMetadata<Trait>,
+    type_id: TypeId
+}
+
+/// A box of a trait object that is a thin pointer, keeping the vtable and the [TypeId](TypeId) of the object in the allocation instead.
+/// 
+/// The vtable is rewritten in place when upcasting or veecasting, and downcasts compare the stored [TypeId](TypeId).
+/// 
+/// The casts are inherent methods rather than impls of [Upcast](crate::upcast::Upcast), [Downcast](crate::downcast::Downcast), [DowncastFrom](crate::downcast::DowncastFrom) or [Veecast](crate::veecast::Veecast).
+/// Those traits are implemented for the object itself and take it as `Box<Self>`, `Rc<Self>` or `Arc<Self>`, so an impl for `ThinBox` would cast a `Box<ThinBox<Trait>>`,
+/// and their blanket impls already cover every type, leaving no room for one.
+/// The reference casts still work on the object through [Deref](Deref), as in `DowncastRef::downcast_ref(&*thin_box)`.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::ThinBox;
+/// use core::any::Any;
+/// 
+/// trait EntityObj
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// trait PlayerObj: EntityObj {}
+/// trait ControlObj {}
+/// 
+/// #[derive(PartialEq, Debug)]
+/// struct Human;
+/// 
+/// impl EntityObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "human"
+///     }
+/// }
+/// impl PlayerObj for Human {}
+/// impl ControlObj for Human {}
+/// 
+/// let player = ThinBox::<dyn PlayerObj>::new(Human);
+/// assert_eq!(size_of_val(&player), size_of::<usize>());
+/// 
+/// let entity: ThinBox<dyn EntityObj> = player.upcast();
+/// assert_eq!(entity.name(), "human");
+/// 
+/// let control: ThinBox<dyn ControlObj> = entity.veecast::<Human, _>().ok().unwrap();
+/// 
+/// assert_eq!(control.downcast::<Human>().ok(), Some(Box::new(Human)));
+/// ```
+pub struct ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    header: NonNull<Header<Trait>>,
+    marker: PhantomData<Trait>
+}
+
+// SAFETY: The box owns its object, just like `Box<Trait>`.
+unsafe impl<Trait> Send for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + Send + ?Sized {}
+// SAFETY: The box owns its object, just like `Box<Trait>`.
+unsafe impl<Trait> Sync for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + Sync + ?Sized {}
+// The object is never moved out of its allocation, just like `Box<Trait>`.
+impl<Trait> Unpin for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized {}
+
+impl<Trait> ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    /// Boxes an object as the trait object `Trait`.
+    pub fn new<Struct>(value: Struct) -> Self
+    where
+        Struct: Is<Trait> + 'static
+    {
+        let (layout, offset) = Self::layout(Layout::new::<Struct>());
+        // SAFETY: The layout is never zero-sized, as it contains the header.
+        let header = NonNull::new(unsafe {alloc(layout)})
+            .unwrap_or_else(|| handle_alloc_error(layout))
+            .cast::<Header<Trait>>();
+        // SAFETY: The allocation fits the header at its start, and the object at the offset.
+        unsafe {
+            header.write(Header {
+                vtable: vtable_for::<Struct, Trait>(),
+                type_id: TypeId::of::<Struct>()
+            });
+            header.cast::<u8>()
+                .add(offset)
+                .cast::<Struct>()
+                .write(value);
+        }
+        Self {
+            header,
+            marker: PhantomData
+        }
+    }
+
+    /// Gets the [TypeId](TypeId) of the object.
+    pub fn type_id(&self) -> TypeId
+    {
+        self.header().type_id
+    }
+
+    /// Checks if the object is of the struct `Struct`.
+    pub fn is<Struct>(&self) -> bool
+    where
+        Struct: 'static
+    {
+        self.type_id() == TypeId::of::<Struct>()
+    }
+
+    /// Upcasts the box into the trait object `To`, rewriting the vtable in place.
+    pub fn upcast<To>(self) -> ThinBox<To>
+    where
+        Trait: Is<To>,
+        To: Pointee<Metadata = DynMetadata<To>> + ?Sized
+    {
+        let vtable = ptr::metadata(Trait::as_obj_ptr(self.as_ptr()));
+        // SAFETY: The object implements `To`, which the vtable is made for.
+        unsafe {self.with_vtable(vtable)}
+    }
+
+    /// Downcasts a reference into the struct `Struct`.
+    pub fn downcast_ref<Struct>(&self) -> Option<&Struct>
+    where
+        Struct: 'static
+    {
+        // SAFETY: The object is of the struct `Struct`.
+        self.is::<Struct>().then(|| unsafe {&*self.as_ptr().cast::<Struct>()})
+    }
+
+    /// Downcasts a mutable reference into the struct `Struct`.
+    pub fn downcast_mut<Struct>(&mut self) -> Option<&mut Struct>
+    where
+        Struct: 'static
+    {
+        // SAFETY: The object is of the struct `Struct`.
+        self.is::<Struct>().then(|| unsafe {&mut *self.as_mut_ptr().cast::<Struct>()})
+    }
+
+    /// Downcasts the box into the struct `Struct`, moving the object into a regular box.
+    /// 
+    /// On failure, the original box is given back.
+    pub fn downcast<Struct>(self) -> Result<Box<Struct>, Self>
+    where
+        Struct: 'static
+    {
+        if !self.is::<Struct>()
+        {
+            return Err(self)
+        }
+        let this = mem::ManuallyDrop::new(self);
+        let (layout, _) = Self::layout(Layout::new::<Struct>());
+        // SAFETY: The object is of the struct `Struct`, and is moved out before the allocation is freed without dropping it.
+        unsafe {
+            let value = this.as_ptr().cast::<Struct>().read();
+            dealloc(this.header.as_ptr().cast(), layout);
+            Ok(Box::new(value))
+        }
+    }
+
+    /// Casts a reference into the trait object `To`, using the mutual struct `Struct`.
+    pub fn veecast_ref<Struct, To>(&self) -> Option<&To>
+    where
+        Struct: Is<To> + 'static,
+        To: ?Sized
+    {
+        self.downcast_ref::<Struct>().map(|vee| vee.as_obj())
+    }
+
+    /// Casts a mutable reference into the trait object `To`, using the mutual struct `Struct`.
+    pub fn veecast_mut<Struct, To>(&mut self) -> Option<&mut To>
+    where
+        Struct: Is<To> + 'static,
+        To: ?Sized
+    {
+        self.downcast_mut::<Struct>().map(|vee| vee.as_obj_mut())
+    }
+
+    /// Casts the box into the trait object `To`, using the mutual struct `Struct`, rewriting the vtable in place.
+    /// 
+    /// On failure, the original box is given back.
+    pub fn veecast<Struct, To>(self) -> Result<ThinBox<To>, Self>
+    where
+        Struct: Is<To> + 'static,
+        To: Pointee<Metadata = DynMetadata<To>> + ?Sized
+    {
+        if !self.is::<Struct>()
+        {
+            return Err(self)
+        }
+        // SAFETY: The object is of the struct `Struct`, which the vtable is made for.
+        Ok(unsafe {self.with_vtable(vtable_for::<Struct, To>())})
+    }
+
+    fn header(&self) -> &Header<Trait>
+    {
+        // SAFETY: The header is written on creation, and lives as long as the box.
+        unsafe {self.header.as_ref()}
+    }
+
+    fn layout(value: Layout) -> (Layout, usize)
+    {
+        let (layout, offset) = Layout::new::<Header<Trait>>()
+            .extend(value)
+            .unwrap();
+        (layout.pad_to_align(), offset)
+    }
+
+    fn as_ptr(&self) -> *const Trait
+    {
+        self.as_mut_ptr()
+    }
+
+    fn as_mut_ptr(&self) -> *mut Trait
+    {
+        let vtable = self.header().vtable;
+        let (_, offset) = Self::layout(vtable.layout());
+        // SAFETY: The object is at the offset in the allocation.
+        let data = unsafe {self.header.as_ptr().cast::<u8>().add(offset)};
+        ptr::from_raw_parts_mut(data, vtable)
+    }
+
+    /// Replaces the vtable in the header, turning the box into a box of the trait object `To`.
+    /// 
+    /// # Safety
+    /// The vtable must be made for the struct of the object.
+    unsafe fn with_vtable<To>(self, vtable: DynMetadata<To>) -> ThinBox<To>
+    where
+        To: Pointee<Metadata = DynMetadata<To>> + ?Sized
+    {
+        let this = mem::ManuallyDrop::new(self);
+        let header = this.header.cast::<Header<To>>();
+        // SAFETY: The layout of the header doesn't depend on the trait object, and the object's layout stays the same.
+        unsafe {(&raw mut (*header.as_ptr()).vtable).write(vtable)};
+        ThinBox {
+            header,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<Trait> Deref for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    type Target = Trait;
+
+    fn deref(&self) -> &Trait
+    {
+        // SAFETY: The object lives as long as the box.
+        unsafe {&*self.as_ptr()}
+    }
+}
+
+impl<Trait> DerefMut for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    fn deref_mut(&mut self) -> &mut Trait
+    {
+        // SAFETY: The object lives as long as the box, which is borrowed mutably.
+        unsafe {&mut *self.as_mut_ptr()}
+    }
+}
+
+impl<Trait> Drop for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + ?Sized
+{
+    fn drop(&mut self)
+    {
+        let (layout, _) = Self::layout(self.header().vtable.layout());
+        // SAFETY: The object is dropped once, before the allocation is freed.
+        unsafe {
+            ptr::drop_in_place(self.as_mut_ptr());
+            dealloc(self.header.as_ptr().cast(), layout);
+        }
+    }
+}
+
+impl<Trait> fmt::Debug for ThinBox<Trait>
+where
+    Trait: Pointee<Metadata = DynMetadata<Trait>> + fmt::Debug + ?Sized
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        (**self).fmt(f)
+    }
+}
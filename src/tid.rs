@@ -0,0 +1,78 @@
+use core::any::TypeId;
+
+/// A type with at most one lifetime parameter, identified by a `'static` marker type.
+/// 
+/// This is what makes non-`'static` objects downcastable with [DowncastTid](crate::downcast::DowncastTid),
+/// where [Any](core::any::Any) would require `'static`.
+/// It should be implemented with [impl_tid](crate::impl_tid).
+/// 
+/// # Safety
+/// 
+/// [Static](Tid::Static) must be unique to Self's type constructor, and is conventionally Self with its lifetime replaced by `'static`.
+/// The trait must only be implemented as `Tid<'a> for T<'a>`, so that the lifetime of the trait is the lifetime of the type.
+pub unsafe trait Tid<'a>: 'a
+{
+    /// The `'static` marker type identifying Self.
+    type Static: ?Sized + 'static;
+
+    /// Returns the [TypeId](TypeId) of the marker type.
+    fn tid() -> TypeId
+    {
+        TypeId::of::<Self::Static>()
+    }
+}
+
+/// A trait for viewing the [Tid](Tid) of an object, preserving its concrete type.
+/// 
+/// Object traits must extend this to support lifetime-aware downcasts. It is implemented for every type implementing [Tid](Tid).
+/// 
+/// # Safety
+/// 
+/// [self_tid](AsTid::self_tid) must return the [Tid::tid](Tid::tid) of the concrete type.
+pub unsafe trait AsTid<'a>: 'a
+{
+    /// Returns the [TypeId](TypeId) of the marker type of the concrete type.
+    fn self_tid(self: &Self) -> TypeId;
+}
+unsafe impl<'a, T> AsTid<'a> for T
+where
+    T: Tid<'a>
+{
+    fn self_tid(self: &Self) -> TypeId
+    {
+        T::tid()
+    }
+}
+
+/// Implements [Tid](Tid) for a type with a single lifetime parameter, or no lifetime parameters.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{impl_tid, Tid};
+/// 
+/// struct Ident<'src>
+/// {
+///     name: &'src str
+/// }
+/// struct Eof;
+/// 
+/// impl_tid!(Ident<'src>);
+/// impl_tid!(Eof);
+/// 
+/// assert_eq!(<Ident<'_> as Tid<'_>>::tid(), core::any::TypeId::of::<Ident<'static>>());
+/// ```
+#[macro_export]
+macro_rules! impl_tid {
+    ($type:ident < $lt:lifetime >) => {
+        unsafe impl<$lt> spellcast::Tid<$lt> for $type<$lt>
+        {
+            type Static = $type<'static>;
+        }
+    };
+    ($type:ty) => {
+        unsafe impl<'a> spellcast::Tid<'a> for $type
+        {
+            type Static = $type;
+        }
+    };
+}
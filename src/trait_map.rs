@@ -0,0 +1,259 @@
+use core::{any::{Any, TypeId}, marker::PhantomData};
+
+use alloc::{boxed::Box, collections::BTreeMap};
+
+use crate::{downcast::{DowncastFrom, DowncastFromRef}, upcast::Upcast};
+
+/// Casts of an entry's object into another trait object, through its concrete type.
+struct Upcaster<Obj, To>
+where
+    Obj: ?Sized,
+    To: ?Sized
+{
+    upcast_ref: fn(&Obj) -> &To,
+    upcast_mut: fn(&mut Obj) -> &mut To
+}
+
+fn upcast_ref<Struct, Obj, To>(from: &Obj) -> &To
+where
+    Struct: DowncastFromRef<Obj> + Upcast<To> + 'static,
+    Obj: ?Sized,
+    To: ?Sized
+{
+    Struct::downcast_from_ref(from)
+        .unwrap()
+        .upcast_ref()
+}
+
+fn upcast_mut<Struct, Obj, To>(from: &mut Obj) -> &mut To
+where
+    Struct: DowncastFromRef<Obj> + Upcast<To> + 'static,
+    Obj: ?Sized,
+    To: ?Sized
+{
+    Struct::downcast_from_mut(from)
+        .unwrap()
+        .upcast_mut()
+}
+
+struct Entry<Obj>
+where
+    Obj: ?Sized
+{
+    object: Box<Obj>,
+    upcasters: BTreeMap<TypeId, Box<dyn Any>>
+}
+
+/// A map of objects keyed by their concrete type, holding at most one object of each type.
+/// 
+/// Objects can be looked up by their concrete type, or iterated as any trait object they have been declared to implement on insertion.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{TraitMap, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait Service: Any {}
+/// impl_object!(Service);
+/// trait Renderable
+/// {
+///     fn render(&self) -> String;
+/// }
+/// 
+/// struct Window;
+/// struct Logger
+/// {
+///     lines: usize
+/// }
+/// 
+/// impl Service for Window {}
+/// impl Service for Logger {}
+/// impl Renderable for Window
+/// {
+///     fn render(&self) -> String
+///     {
+///         "window".to_string()
+///     }
+/// }
+/// 
+/// impl_is!(Window: dyn Service, dyn Renderable);
+/// impl_is!(Logger: dyn Service);
+/// 
+/// let mut services = TraitMap::<dyn Service>::new();
+/// services.insert(Window)
+///     .implements::<dyn Renderable>();
+/// services.insert(Logger {lines: 0});
+/// 
+/// services.get_mut::<Logger>().unwrap().lines += 1;
+/// assert_eq!(services.get::<Logger>().unwrap().lines, 1);
+/// 
+/// let rendered: Vec<String> = services.iter_as::<dyn Renderable>()
+///     .map(|renderable| renderable.render())
+///     .collect();
+/// assert_eq!(rendered, ["window"]);
+/// 
+/// assert!(services.remove::<Window>().is_some());
+/// assert_eq!(services.iter_as::<dyn Renderable>().count(), 0);
+/// ```
+pub struct TraitMap<Obj>
+where
+    Obj: ?Sized + 'static
+{
+    entries: BTreeMap<TypeId, Entry<Obj>>
+}
+
+/// A freshly inserted entry of a [TraitMap](TraitMap), to declare the trait objects it can be iterated as.
+pub struct TraitMapEntry<'a, Obj, Struct>
+where
+    Obj: ?Sized + 'static
+{
+    entry: &'a mut Entry<Obj>,
+    marker: PhantomData<fn() -> Struct>
+}
+
+impl<'a, Obj, Struct> TraitMapEntry<'a, Obj, Struct>
+where
+    Obj: ?Sized + 'static,
+    Struct: DowncastFromRef<Obj> + 'static
+{
+    /// Declares that the entry implements the trait object `To`, so that it is yielded by [TraitMap::iter_as](TraitMap::iter_as).
+    pub fn implements<To>(self) -> Self
+    where
+        Struct: Upcast<To>,
+        To: ?Sized + 'static
+    {
+        let upcaster = Upcaster::<Obj, To> {
+            upcast_ref: upcast_ref::<Struct, Obj, To>,
+            upcast_mut: upcast_mut::<Struct, Obj, To>
+        };
+        self.entry.upcasters.insert(TypeId::of::<To>(), Box::new(upcaster));
+        self
+    }
+}
+
+impl<Obj> TraitMap<Obj>
+where
+    Obj: ?Sized + 'static
+{
+    /// Creates an empty map.
+    pub const fn new() -> Self
+    {
+        Self {
+            entries: BTreeMap::new()
+        }
+    }
+
+    /// Returns the number of objects in the map.
+    pub fn len(&self) -> usize
+    {
+        self.entries.len()
+    }
+
+    /// Checks if the map is empty.
+    pub fn is_empty(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+
+    /// Checks if the map holds an object of the struct `Struct`.
+    pub fn contains<Struct>(&self) -> bool
+    where
+        Struct: 'static
+    {
+        self.entries.contains_key(&TypeId::of::<Struct>())
+    }
+
+    /// Inserts an object, replacing any object of the same struct.
+    /// 
+    /// The trait objects it can be iterated as are declared on the returned entry.
+    pub fn insert<Struct>(&mut self, value: Struct) -> TraitMapEntry<'_, Obj, Struct>
+    where
+        Struct: Upcast<Obj> + DowncastFromRef<Obj> + 'static
+    {
+        let entry = Entry {
+            object: Box::new(value).upcast(),
+            upcasters: BTreeMap::new()
+        };
+        let entry = match self.entries.entry(TypeId::of::<Struct>())
+        {
+            alloc::collections::btree_map::Entry::Vacant(vacant) => vacant.insert(entry),
+            alloc::collections::btree_map::Entry::Occupied(mut occupied) => {
+                occupied.insert(entry);
+                occupied.into_mut()
+            }
+        };
+        TraitMapEntry {
+            entry,
+            marker: PhantomData
+        }
+    }
+
+    /// Gets a reference to the object of the struct `Struct`.
+    pub fn get<Struct>(&self) -> Option<&Struct>
+    where
+        Struct: DowncastFromRef<Obj> + 'static
+    {
+        Struct::downcast_from_ref(&*self.entries.get(&TypeId::of::<Struct>())?.object)
+    }
+
+    /// Gets a mutable reference to the object of the struct `Struct`.
+    pub fn get_mut<Struct>(&mut self) -> Option<&mut Struct>
+    where
+        Struct: DowncastFromRef<Obj> + 'static
+    {
+        Struct::downcast_from_mut(&mut *self.entries.get_mut(&TypeId::of::<Struct>())?.object)
+    }
+
+    /// Removes the object of the struct `Struct` from the map, and returns it.
+    pub fn remove<Struct>(&mut self) -> Option<Box<Struct>>
+    where
+        Struct: DowncastFrom<Obj, Obj> + 'static,
+        Obj: crate::Is<Obj>
+    {
+        let entry = self.entries.remove(&TypeId::of::<Struct>())?;
+        Struct::downcast_from(entry.object).ok()
+    }
+
+    /// Iterates over all objects in the map.
+    pub fn iter(&self) -> impl Iterator<Item = &Obj>
+    {
+        self.entries.values()
+            .map(|entry| &*entry.object)
+    }
+
+    /// Iterates over all objects in the map which have been declared to implement the trait object `To`.
+    pub fn iter_as<To>(&self) -> impl Iterator<Item = &To>
+    where
+        To: ?Sized + 'static
+    {
+        self.entries.values()
+            .filter_map(|entry| {
+                let upcaster = entry.upcasters.get(&TypeId::of::<To>())?
+                    .downcast_ref::<Upcaster<Obj, To>>()?;
+                Some((upcaster.upcast_ref)(&*entry.object))
+            })
+    }
+
+    /// Mutably iterates over all objects in the map which have been declared to implement the trait object `To`.
+    pub fn iter_as_mut<To>(&mut self) -> impl Iterator<Item = &mut To>
+    where
+        To: ?Sized + 'static
+    {
+        self.entries.values_mut()
+            .filter_map(|entry| {
+                let upcaster = entry.upcasters.get(&TypeId::of::<To>())?
+                    .downcast_ref::<Upcaster<Obj, To>>()?;
+                Some((upcaster.upcast_mut)(&mut *entry.object))
+            })
+    }
+}
+
+impl<Obj> Default for TraitMap<Obj>
+where
+    Obj: ?Sized + 'static
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
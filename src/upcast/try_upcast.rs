@@ -1,5 +1,10 @@
+use alloc::boxed::Box;
+
 use super::*;
 
+/// A trait for fallibly upcasting boxed objects into trait objects, giving the object back as `Obj` on failure.
+/// 
+/// Like [TryUpcastRef](TryUpcastRef), this is only implemented for objects implementing [Upcast](Upcast), so it always succeeds.
 pub trait TryUpcast<To, Obj>: Is<Obj> + TryUpcastRef<To>
 where
     To: ?Sized,
@@ -7,7 +12,7 @@ where
 {
     fn try_upcast(self: Box<Self>) -> Result<Box<To>, Box<Obj>>
     {
-        Err(self)
+        Err(self.into_obj())
     }
 }
 impl<From, To, Obj> TryUpcast<To, Obj> for From
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use super::*;
 
 pub trait TryUpcastFrom<From, Obj>: TryUpcastFromRef<From>
@@ -1,11 +1,39 @@
 use super::*;
 
+/// A trait for fallibly upcasting objects into trait objects.
+/// 
+/// This is only implemented for objects implementing [Upcast](Upcast), so it always succeeds, and [is_of](TryUpcastRef::is_of) is always true.
+/// 
+/// There is deliberately no fallback impl that fails for every other pair of types.
+/// Whether a type unsizes into a trait object may depend on lifetimes, so choosing between such impls by specialization isn't sound.
+/// To ask at runtime whether an object implements a trait, use [Implements](crate::Implements) instead.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{upcast::*, impl_is};
+/// 
+/// trait PlayerObj {}
+/// 
+/// struct Human;
+/// 
+/// impl PlayerObj for Human {}
+/// 
+/// impl_is!(Human: dyn PlayerObj);
+/// 
+/// let mut human = Human;
+/// 
+/// assert!(TryUpcastRef::<dyn PlayerObj>::is_of(&human));
+/// assert!(TryUpcastRef::<dyn PlayerObj>::try_upcast_mut(&mut human).is_some());
+/// ```
 pub trait TryUpcastRef<To>: Is<To>
 where
     To: ?Sized
 {
+    /// Checks if the object can be upcast into the trait object `To`.
     fn is_of(self: &Self) -> bool;
+    /// Upcasts a reference into the trait object `To`, if possible.
     fn try_upcast_ref(self: &Self) -> Option<&To>;
+    /// Upcasts a mutable reference into the trait object `To`, if possible.
     fn try_upcast_mut(self: &mut Self) -> Option<&mut To>;
 }
 impl<From, To> TryUpcastRef<To> for From
@@ -1,3 +1,7 @@
+use core::pin::Pin;
+
+use alloc::{boxed::Box, rc::{self, Rc}, sync::{self, Arc}};
+
 use super::*;
 
 pub trait Upcast<To>: TryUpcastRef<To>
@@ -6,6 +10,11 @@ where To: ?Sized
     fn upcast_ref(self: &Self) -> &To;
     fn upcast_mut(self: &mut Self) -> &mut To;
     fn upcast(self: Box<Self>) -> Box<To>;
+    fn upcast_pin(self: Pin<Box<Self>>) -> Pin<Box<To>>;
+    fn upcast_rc(self: Rc<Self>) -> Rc<To>;
+    fn upcast_arc(self: Arc<Self>) -> Arc<To>;
+    fn upcast_weak(from: rc::Weak<Self>) -> rc::Weak<To>;
+    fn upcast_weak_arc(from: sync::Weak<Self>) -> sync::Weak<To>;
 }
 impl<From, To> Upcast<To> for From
 where
@@ -14,14 +23,34 @@ where
 {
     fn upcast_ref(self: &Self) -> &To
     {
-        self
+        self.as_obj()
     }
     fn upcast_mut(self: &mut Self) -> &mut To
     {
-        self
+        self.as_obj_mut()
     }
     fn upcast(self: Box<Self>) -> Box<To>
     {
-        self
+        self.into_obj()
+    }
+    fn upcast_pin(self: Pin<Box<Self>>) -> Pin<Box<To>>
+    {
+        self.into_obj_pin()
+    }
+    fn upcast_rc(self: Rc<Self>) -> Rc<To>
+    {
+        self.into_obj_rc()
+    }
+    fn upcast_arc(self: Arc<Self>) -> Arc<To>
+    {
+        self.into_obj_arc()
+    }
+    fn upcast_weak(from: rc::Weak<Self>) -> rc::Weak<To>
+    {
+        Self::into_obj_weak(from)
+    }
+    fn upcast_weak_arc(from: sync::Weak<Self>) -> sync::Weak<To>
+    {
+        Self::into_obj_weak_arc(from)
     }
 }
\ No newline at end of file
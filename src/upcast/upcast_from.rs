@@ -1,10 +1,14 @@
+use core::pin::Pin;
+
+use alloc::{boxed::Box, rc::{self, Rc}, sync::{self, Arc}};
+
 use super::*;
 
 /// A trait for upcasting types into a trait object
 /// 
 /// # Examples
 /// ```rust
-/// use spellcast::upcast::*;
+/// use spellcast::{upcast::*, impl_is};
 /// 
 /// trait EntityObj {}
 /// trait PlayerObj: EntityObj {}
@@ -14,6 +18,9 @@ use super::*;
 /// impl EntityObj for Human {}
 /// impl PlayerObj for Human {}
 /// 
+/// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+/// impl_is!(dyn PlayerObj: dyn EntityObj);
+/// 
 /// let human = Box::new(Human);
 /// 
 /// let player = <dyn PlayerObj>::upcast_from(human);
@@ -27,7 +34,7 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::upcast::*;
+    /// use spellcast::{upcast::*, impl_is};
     /// 
     /// trait EntityObj {}
     /// trait PlayerObj: EntityObj {}
@@ -37,6 +44,9 @@ where
     /// impl EntityObj for Human {}
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
     /// let human = Box::new(Human);
     /// 
     /// let player = <dyn PlayerObj>::upcast_from_ref(&*human);
@@ -48,7 +58,7 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::upcast::*;
+    /// use spellcast::{upcast::*, impl_is};
     /// 
     /// trait EntityObj {}
     /// trait PlayerObj: EntityObj {}
@@ -58,6 +68,9 @@ where
     /// impl EntityObj for Human {}
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
     /// let mut human = Box::new(Human);
     /// 
     /// let player = <dyn PlayerObj>::upcast_from_mut(&mut *human);
@@ -69,7 +82,7 @@ where
     /// 
     /// # Examples
     /// ```rust
-    /// use spellcast::upcast::*;
+    /// use spellcast::{upcast::*, impl_is};
     /// 
     /// trait EntityObj {}
     /// trait PlayerObj: EntityObj {}
@@ -79,12 +92,143 @@ where
     /// impl EntityObj for Human {}
     /// impl PlayerObj for Human {}
     /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
     /// let human = Box::new(Human);
     /// 
     /// let player = <dyn PlayerObj>::upcast_from(human);
     /// let entity = <dyn EntityObj>::upcast_from(player);
     /// ```
     fn upcast_from(from: Box<From>) -> Box<Self>;
+    
+    /// Upcasts a pinned type into a pinned trait object
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{upcast::*, impl_is};
+    /// 
+    /// trait EntityObj {}
+    /// trait PlayerObj: EntityObj {}
+    /// 
+    /// struct Human;
+    /// 
+    /// impl EntityObj for Human {}
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
+    /// let human = Box::pin(Human);
+    /// 
+    /// let player = <dyn PlayerObj>::upcast_from_pin(human);
+    /// let entity = <dyn EntityObj>::upcast_from_pin(player);
+    /// ```
+    fn upcast_from_pin(from: Pin<Box<From>>) -> Pin<Box<Self>>;
+    
+    /// Upcasts a type into a trait object
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{upcast::*, impl_is};
+    /// use std::rc::Rc;
+    /// 
+    /// trait EntityObj {}
+    /// trait PlayerObj: EntityObj {}
+    /// 
+    /// struct Human;
+    /// 
+    /// impl EntityObj for Human {}
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
+    /// let human = Rc::new(Human);
+    /// 
+    /// let player = <dyn PlayerObj>::upcast_from_rc(human);
+    /// let entity = <dyn EntityObj>::upcast_from_rc(player);
+    /// ```
+    fn upcast_from_rc(from: Rc<From>) -> Rc<Self>;
+    
+    /// Upcasts a type into a trait object
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{upcast::*, impl_is};
+    /// use std::sync::Arc;
+    /// 
+    /// trait EntityObj {}
+    /// trait PlayerObj: EntityObj {}
+    /// 
+    /// struct Human;
+    /// 
+    /// impl EntityObj for Human {}
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
+    /// let human = Arc::new(Human);
+    /// 
+    /// let player = <dyn PlayerObj>::upcast_from_arc(human);
+    /// let entity = <dyn EntityObj>::upcast_from_arc(player);
+    /// ```
+    fn upcast_from_arc(from: Arc<From>) -> Arc<Self>;
+    
+    /// Upcasts a type into a trait object
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{upcast::*, impl_is};
+    /// use std::rc::Rc;
+    /// 
+    /// trait EntityObj {}
+    /// trait PlayerObj: EntityObj {}
+    /// 
+    /// struct Human;
+    /// 
+    /// impl EntityObj for Human {}
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
+    /// let human = Rc::new(Human);
+    /// 
+    /// let player = <dyn PlayerObj>::upcast_from_weak(Rc::downgrade(&human));
+    /// let entity = <dyn EntityObj>::upcast_from_weak(player);
+    /// 
+    /// assert!(entity.upgrade().is_some());
+    /// ```
+    fn upcast_from_weak(from: rc::Weak<From>) -> rc::Weak<Self>;
+    
+    /// Upcasts a type into a trait object
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use spellcast::{upcast::*, impl_is};
+    /// use std::sync::Arc;
+    /// 
+    /// trait EntityObj {}
+    /// trait PlayerObj: EntityObj {}
+    /// 
+    /// struct Human;
+    /// 
+    /// impl EntityObj for Human {}
+    /// impl PlayerObj for Human {}
+    /// 
+    /// impl_is!(Human: dyn EntityObj, dyn PlayerObj);
+    /// impl_is!(dyn PlayerObj: dyn EntityObj);
+    /// 
+    /// let human = Arc::new(Human);
+    /// 
+    /// let player = <dyn PlayerObj>::upcast_from_weak_arc(Arc::downgrade(&human));
+    /// let entity = <dyn EntityObj>::upcast_from_weak_arc(player);
+    /// 
+    /// assert!(entity.upgrade().is_some());
+    /// ```
+    fn upcast_from_weak_arc(from: sync::Weak<From>) -> sync::Weak<Self>;
 }
 
 impl<From, To> UpcastFrom<From> for To
@@ -104,4 +248,24 @@ where
     {
         from.upcast()
     }
+    fn upcast_from_pin(from: Pin<Box<From>>) -> Pin<Box<Self>>
+    {
+        from.upcast_pin()
+    }
+    fn upcast_from_rc(from: Rc<From>) -> Rc<Self>
+    {
+        from.upcast_rc()
+    }
+    fn upcast_from_arc(from: Arc<From>) -> Arc<Self>
+    {
+        from.upcast_arc()
+    }
+    fn upcast_from_weak(from: rc::Weak<From>) -> rc::Weak<Self>
+    {
+        From::upcast_weak(from)
+    }
+    fn upcast_from_weak_arc(from: sync::Weak<From>) -> sync::Weak<Self>
+    {
+        From::upcast_weak_arc(from)
+    }
 }
\ No newline at end of file
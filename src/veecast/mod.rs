@@ -1,7 +1,10 @@
 moddef::moddef!(
     flat(pub) mod {
         veecast_ref,
-        veecast_
+        veecast_,
+        veecast_candidates,
+        veecast_any,
+        veecaster for cfg(feature = "std")
     }
 );
 
@@ -1,3 +1,7 @@
+use core::pin::Pin;
+
+use alloc::boxed::Box;
+
 use crate::{downcast::Downcast, upcast::Upcast};
 
 use super::*;
@@ -6,20 +10,24 @@ use super::*;
 /// 
 /// # Example
 /// ```rust
-/// use spellcast::veecast::*;
+/// use spellcast::{veecast::*, impl_object, impl_is};
 /// use core::any::Any;
 /// 
 /// trait ControlObj: Any {}
+/// impl_object!(ControlObj);
 /// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
 /// 
 /// struct Human;
 /// 
 /// impl ControlObj for Human {}
 /// impl PlayerObj for Human {}
 /// 
+/// impl_is!(Human: dyn ControlObj, dyn PlayerObj);
+/// 
 /// let player = Box::new(Human) as Box<dyn PlayerObj>;
 /// 
-/// let control: Option<&dyn ControlObj> = (&*human).veecast_ref::<Human>();
+/// let control: Option<&dyn ControlObj> = (&*player).veecast_ref::<Human>();
 /// ```
 pub trait Veecast<To, Obj>: VeecastRef<To>
 where
@@ -30,6 +38,40 @@ where
     where
         Self: Downcast<Struct, Obj>,
         Struct: Upcast<To>;
+
+    /// Casts between two pinned trait objects, using a mutual struct.
+    /// 
+    /// The object is never moved or unpinned in the process, and on failure, the original pinned object is given back.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use spellcast::{veecast::*, impl_object, impl_is};
+    /// use core::{any::Any, marker::PhantomPinned, pin::Pin};
+    /// 
+    /// trait TaskObj: Any {}
+    /// impl_object!(TaskObj);
+    /// trait TimerObj: Any {}
+    /// impl_object!(TimerObj);
+    /// 
+    /// struct Sleep
+    /// {
+    ///     _pin: PhantomPinned
+    /// }
+    /// 
+    /// impl TaskObj for Sleep {}
+    /// impl TimerObj for Sleep {}
+    /// 
+    /// impl_is!(Sleep: dyn TaskObj, dyn TimerObj);
+    /// 
+    /// let task = Box::pin(Sleep {_pin: PhantomPinned}) as Pin<Box<dyn TaskObj>>;
+    /// 
+    /// let timer: Result<Pin<Box<dyn TimerObj>>, Pin<Box<dyn TaskObj>>> = task.veecast_pin::<Sleep>();
+    /// assert!(timer.is_ok());
+    /// ```
+    fn veecast_pin<Struct>(self: Pin<Box<Self>>) -> Result<Pin<Box<To>>, Pin<Box<Obj>>>
+    where
+        Self: Downcast<Struct, Obj>,
+        Struct: Upcast<To>;
 }
 impl<From, To, Obj> Veecast<To, Obj> for From
 where
@@ -44,4 +86,11 @@ where
     {
         self.downcast().map(|vee| vee.upcast())
     }
+    fn veecast_pin<Struct>(self: Pin<Box<Self>>) -> Result<Pin<Box<To>>, Pin<Box<Obj>>>
+    where
+        Self: Downcast<Struct, Obj>,
+        Struct: Upcast<To>
+    {
+        self.downcast_pin().map(|vee| vee.upcast_pin())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,88 @@
+use alloc::boxed::Box;
+
+use super::*;
+
+/// A trait for casting between two trait objects, through the first of several candidate structs that matches.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{veecast::*, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// trait ControlObj: Any
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// impl_object!(ControlObj);
+/// 
+/// struct Human;
+/// struct Bot;
+/// struct Npc;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl PlayerObj for Npc {}
+/// impl ControlObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "human"
+///     }
+/// }
+/// impl ControlObj for Bot
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "bot"
+///     }
+/// }
+/// 
+/// impl_is!(Human: dyn PlayerObj, dyn ControlObj);
+/// impl_is!(Bot: dyn PlayerObj, dyn ControlObj);
+/// impl_is!(Npc: dyn PlayerObj);
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// 
+/// let control = (*player).veecast_any_ref::<(Human, Bot), dyn ControlObj>().unwrap();
+/// assert_eq!(control.name(), "bot");
+/// 
+/// let player = Box::new(Npc) as Box<dyn PlayerObj>;
+/// 
+/// assert!(player.veecast_any::<(Human, Bot), dyn ControlObj>().is_err());
+/// ```
+pub trait VeecastAny
+{
+    /// Casts a reference into the trait object `To`, through the first of the candidate structs that matches.
+    fn veecast_any_ref<Tuple, To>(self: &Self) -> Option<&To>
+    where
+        Tuple: VeecastCandidates<Self, To>,
+        To: ?Sized
+    {
+        Tuple::veecast_any_ref(self)
+    }
+
+    /// Casts a mutable reference into the trait object `To`, through the first of the candidate structs that matches.
+    fn veecast_any_mut<Tuple, To>(self: &mut Self) -> Option<&mut To>
+    where
+        Tuple: VeecastCandidates<Self, To>,
+        To: ?Sized
+    {
+        Tuple::veecast_any_mut(self)
+    }
+
+    /// Casts a box into the trait object `To`, through the first of the candidate structs that matches.
+    /// 
+    /// On failure, the original box is given back.
+    fn veecast_any<Tuple, To>(self: Box<Self>) -> Result<Box<To>, Box<Self>>
+    where
+        Tuple: VeecastCandidates<Self, To>,
+        To: ?Sized
+    {
+        Tuple::veecast_any(self)
+    }
+}
+impl<From> VeecastAny for From
+where
+    From: ?Sized {}
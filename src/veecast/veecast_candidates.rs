@@ -0,0 +1,76 @@
+use alloc::boxed::Box;
+
+use crate::{downcast::DowncastFromRef, upcast::Upcast, AsAny};
+
+/// A tuple of candidate structs to cast an object through, used by [VeecastAny](super::VeecastAny).
+/// 
+/// Implemented for tuples of up to 12 structs.
+pub trait VeecastCandidates<From, To>
+where
+    From: ?Sized,
+    To: ?Sized
+{
+    /// Casts a reference through the first candidate that matches.
+    fn veecast_any_ref(from: &From) -> Option<&To>;
+    /// Casts a mutable reference through the first candidate that matches.
+    fn veecast_any_mut(from: &mut From) -> Option<&mut To>;
+    /// Casts a box through the first candidate that matches.
+    /// 
+    /// On failure, the original box is given back.
+    fn veecast_any(from: Box<From>) -> Result<Box<To>, Box<From>>;
+}
+
+macro_rules! impl_veecast_candidates {
+    ($($t:ident),+) => {
+        impl<From, To, $($t),+> VeecastCandidates<From, To> for ($($t,)+)
+        where
+            From: AsAny + ?Sized,
+            To: ?Sized,
+            $($t: DowncastFromRef<From> + Upcast<To> + 'static),+
+        {
+            fn veecast_any_ref(from: &From) -> Option<&To>
+            {
+                $(
+                    if let Some(vee) = $t::downcast_from_ref(from)
+                    {
+                        return Some(vee.upcast_ref())
+                    }
+                )+
+                None
+            }
+            fn veecast_any_mut(from: &mut From) -> Option<&mut To>
+            {
+                $(
+                    if $t::downcast_from_ref(&*from).is_some()
+                    {
+                        return $t::downcast_from_mut(from).map(|vee| vee.upcast_mut())
+                    }
+                )+
+                None
+            }
+            fn veecast_any(from: Box<From>) -> Result<Box<To>, Box<From>>
+            {
+                $(
+                    if $t::downcast_from_ref(&*from).is_some()
+                    {
+                        return Ok(from.into_any().downcast::<$t>().unwrap().upcast())
+                    }
+                )+
+                Err(from)
+            }
+        }
+    };
+}
+
+impl_veecast_candidates!(A);
+impl_veecast_candidates!(A, B);
+impl_veecast_candidates!(A, B, C);
+impl_veecast_candidates!(A, B, C, D);
+impl_veecast_candidates!(A, B, C, D, E);
+impl_veecast_candidates!(A, B, C, D, E, F);
+impl_veecast_candidates!(A, B, C, D, E, F, G);
+impl_veecast_candidates!(A, B, C, D, E, F, G, H);
+impl_veecast_candidates!(A, B, C, D, E, F, G, H, I);
+impl_veecast_candidates!(A, B, C, D, E, F, G, H, I, J);
+impl_veecast_candidates!(A, B, C, D, E, F, G, H, I, J, K);
+impl_veecast_candidates!(A, B, C, D, E, F, G, H, I, J, K, L);
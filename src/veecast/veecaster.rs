@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+
+use crate::{registry::Caster, upcast::Upcast, AsAny};
+
+/// A runtime list of candidate structs to cast objects through into the trait object `To`.
+/// 
+/// Unlike [VeecastAny](super::VeecastAny), candidates can be added at runtime, e.g. by plugins.
+/// 
+/// # Examples
+/// ```rust
+/// use spellcast::{veecast::Veecaster, impl_object, impl_is};
+/// use core::any::Any;
+/// 
+/// trait PlayerObj: Any {}
+/// impl_object!(PlayerObj);
+/// trait ControlObj
+/// {
+///     fn name(&self) -> &str;
+/// }
+/// 
+/// struct Human;
+/// struct Bot;
+/// 
+/// impl PlayerObj for Human {}
+/// impl PlayerObj for Bot {}
+/// impl ControlObj for Human
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "human"
+///     }
+/// }
+/// impl ControlObj for Bot
+/// {
+///     fn name(&self) -> &str
+///     {
+///         "bot"
+///     }
+/// }
+/// 
+/// impl_is!(Human: dyn PlayerObj, dyn ControlObj);
+/// impl_is!(Bot: dyn PlayerObj, dyn ControlObj);
+/// 
+/// let mut veecaster = Veecaster::<dyn ControlObj>::new()
+///     .with::<Human>();
+/// 
+/// let player = Box::new(Bot) as Box<dyn PlayerObj>;
+/// assert!(veecaster.cast_ref(&*player).is_none());
+/// 
+/// veecaster.register::<Bot>();
+/// 
+/// let control = veecaster.cast_box(player).ok().unwrap();
+/// assert_eq!(control.name(), "bot");
+/// ```
+pub struct Veecaster<To>
+where
+    To: ?Sized + 'static
+{
+    candidates: Vec<Caster<To>>
+}
+
+impl<To> Veecaster<To>
+where
+    To: ?Sized + 'static
+{
+    /// Creates a veecaster without any candidates.
+    pub const fn new() -> Self
+    {
+        Self {
+            candidates: Vec::new()
+        }
+    }
+
+    /// Adds the struct `Struct` as a candidate.
+    pub fn with<Struct>(mut self) -> Self
+    where
+        Struct: Upcast<To> + 'static
+    {
+        self.register::<Struct>();
+        self
+    }
+
+    /// Adds the struct `Struct` as a candidate.
+    pub fn register<Struct>(&mut self)
+    where
+        Struct: Upcast<To> + 'static
+    {
+        self.candidates.push(Caster::of::<Struct>())
+    }
+
+    /// Casts a reference into the trait object `To`, through the first of the candidate structs that matches.
+    pub fn cast_ref<'a, From>(&self, from: &'a From) -> Option<&'a To>
+    where
+        From: AsAny + ?Sized
+    {
+        let from = AsAny::as_any(from);
+        self.candidates.iter()
+            .find_map(|caster| caster.cast_ref(from))
+    }
+
+    /// Casts a mutable reference into the trait object `To`, through the first of the candidate structs that matches.
+    pub fn cast_mut<'a, From>(&self, from: &'a mut From) -> Option<&'a mut To>
+    where
+        From: AsAny + ?Sized
+    {
+        let caster = self.find(&*from)?;
+        caster.cast_mut(AsAny::as_any_mut(from))
+    }
+
+    /// Casts a box into the trait object `To`, through the first of the candidate structs that matches.
+    /// 
+    /// On failure, the original box is given back.
+    pub fn cast_box<From>(&self, from: Box<From>) -> Result<Box<To>, Box<From>>
+    where
+        From: AsAny + ?Sized
+    {
+        match self.find(&*from)
+        {
+            Some(caster) => Ok(caster.cast_box(from.into_any()).ok().unwrap()),
+            None => Err(from)
+        }
+    }
+
+    fn find<From>(&self, from: &From) -> Option<&Caster<To>>
+    where
+        From: AsAny + ?Sized
+    {
+        let from = AsAny::as_any(from);
+        self.candidates.iter()
+            .find(|caster| caster.cast_ref(from).is_some())
+    }
+}
+
+impl<To> Default for Veecaster<To>
+where
+    To: ?Sized + 'static
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<To> Clone for Veecaster<To>
+where
+    To: ?Sized + 'static
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            candidates: self.candidates.clone()
+        }
+    }
+}
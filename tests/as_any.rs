@@ -0,0 +1,38 @@
+use core::any::Any;
+
+use spellcast::{downcast::*, impl_is, impl_object, AsAny};
+
+trait PlayerObj: Any {}
+
+impl_object!(PlayerObj);
+
+#[derive(PartialEq, Debug)]
+struct Human;
+#[derive(PartialEq, Debug)]
+struct Bot;
+
+impl PlayerObj for Human {}
+impl PlayerObj for Bot {}
+
+impl_is!(Human: dyn PlayerObj + Send, dyn PlayerObj + Send + Sync);
+impl_is!(Bot: dyn PlayerObj + Send, dyn PlayerObj + Send + Sync);
+
+#[test]
+fn downcast_send_sync()
+{
+    let player = Box::new(Human) as Box<dyn PlayerObj + Send>;
+
+    let bot: Result<Box<Bot>, Box<dyn PlayerObj + Send>> = player.downcast();
+    let player = bot.err().unwrap();
+
+    let player = std::thread::spawn(move || player).join().unwrap();
+    assert!(player.into_any_send().is::<Human>());
+
+    let player = Box::new(Bot) as Box<dyn PlayerObj + Send + Sync>;
+
+    let human: Result<Box<Human>, Box<dyn PlayerObj + Send + Sync>> = player.downcast();
+    let player = human.err().unwrap();
+
+    assert!((*player).as_any_send_sync().is::<Bot>());
+    assert!(player.into_any_send_sync().is::<Bot>());
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "derive")]
+
+use core::any::Any;
+
+use spellcast::{convert::*, impl_is};
+
+trait PlayerObj: Any
+{
+    fn name(&self) -> &str;
+}
+
+#[derive(PartialEq, Debug)]
+struct Human
+{
+    name: String
+}
+
+#[derive(PartialEq, Debug, ConvertInto)]
+#[convert(into = Human, with = bot_to_human)]
+#[convert(try_into = Npc, alt = dyn PlayerObj)]
+struct Bot
+{
+    id: u32
+}
+
+#[derive(PartialEq, Debug, ConvertInto)]
+#[convert(into = Human)]
+#[convert(try_into = Bot, alt = dyn PlayerObj, with = npc_to_bot)]
+struct Npc
+{
+    id: u32
+}
+
+impl PlayerObj for Human
+{
+    fn name(&self) -> &str
+    {
+        &self.name
+    }
+}
+impl PlayerObj for Bot
+{
+    fn name(&self) -> &str
+    {
+        "bot"
+    }
+}
+impl PlayerObj for Npc
+{
+    fn name(&self) -> &str
+    {
+        "npc"
+    }
+}
+
+impl_is!(Human: dyn PlayerObj);
+impl_is!(Bot: dyn PlayerObj);
+impl_is!(Npc: dyn PlayerObj);
+
+fn bot_to_human(bot: Bot) -> Human
+{
+    Human {
+        name: format!("human {}", bot.id)
+    }
+}
+
+fn npc_to_bot(npc: Npc) -> Result<Bot, Npc>
+{
+    if npc.id == 0
+    {
+        return Err(npc)
+    }
+    Ok(Bot {id: npc.id})
+}
+
+impl From<Npc> for Human
+{
+    fn from(npc: Npc) -> Self
+    {
+        Human {
+            name: format!("npc {}", npc.id)
+        }
+    }
+}
+
+impl TryFrom<Bot> for Npc
+{
+    type Error = Bot;
+
+    fn try_from(bot: Bot) -> Result<Self, Self::Error>
+    {
+        if bot.id % 2 == 1
+        {
+            return Err(bot)
+        }
+        Ok(Npc {id: bot.id})
+    }
+}
+
+#[test]
+fn convert_into_with()
+{
+    let human: Box<Human> = Box::new(Bot {id: 1}).convert_into();
+    assert_eq!(human.name, "human 1");
+}
+
+#[test]
+fn convert_into_from()
+{
+    let human: Box<Human> = Box::new(Npc {id: 2}).convert_into();
+    assert_eq!(human.name, "npc 2");
+}
+
+#[test]
+fn try_convert_into_try_from()
+{
+    let npc: Result<Box<Npc>, Box<dyn PlayerObj>> = Box::new(Bot {id: 2}).try_convert_into();
+    assert_eq!(*npc.ok().unwrap(), Npc {id: 2});
+
+    let npc: Result<Box<Npc>, Box<dyn PlayerObj>> = Box::new(Bot {id: 3}).try_convert_into();
+    assert_eq!(npc.err().unwrap().name(), "bot");
+}
+
+#[test]
+fn try_convert_into_with()
+{
+    let bot: Result<Box<Bot>, Box<dyn PlayerObj>> = Box::new(Npc {id: 4}).try_convert_into();
+    assert_eq!(*bot.ok().unwrap(), Bot {id: 4});
+
+    let bot: Result<Box<Bot>, Box<dyn PlayerObj>> = Box::new(Npc {id: 0}).try_convert_into();
+    assert_eq!(bot.err().unwrap().name(), "npc");
+}
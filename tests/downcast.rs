@@ -0,0 +1,35 @@
+use core::{any::Any, fmt::Debug};
+
+use spellcast::{downcast::*, impl_is, impl_object};
+
+trait PlayerObj: Any + Debug {}
+
+impl_object!(PlayerObj);
+
+#[derive(PartialEq, Debug)]
+struct Human;
+#[derive(PartialEq, Debug)]
+struct Bot;
+
+impl PlayerObj for Human {}
+impl PlayerObj for Bot {}
+
+impl_is!(Human: dyn PlayerObj);
+impl_is!(Bot: dyn PlayerObj);
+
+#[test]
+fn downcast_box()
+{
+    let player = Box::new(Human) as Box<dyn PlayerObj>;
+
+    let bot: Result<Box<Bot>, Box<dyn PlayerObj>> = Bot::downcast_from(player);
+    let player = bot.unwrap_err();
+
+    let human: Result<Box<Human>, Box<dyn PlayerObj>> = Human::downcast_from(player);
+    assert_eq!(*human.unwrap(), Human);
+
+    let player = Box::new(Bot) as Box<dyn PlayerObj>;
+
+    let bot: Result<Box<Bot>, Box<dyn PlayerObj>> = player.downcast();
+    assert_eq!(*bot.unwrap(), Bot);
+}
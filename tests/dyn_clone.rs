@@ -0,0 +1,28 @@
+use spellcast::{impl_is, impl_object, AsAny, DynClone, DynEq};
+
+trait ShapeObj: DynEq + DynClone {}
+
+impl_object!(ShapeObj with PartialEq, Clone);
+
+#[derive(PartialEq, Clone)]
+struct Circle(u32);
+#[derive(PartialEq, Clone)]
+struct Square(u32);
+
+impl ShapeObj for Circle {}
+impl ShapeObj for Square {}
+
+impl_is!(Circle: dyn ShapeObj);
+impl_is!(Square: dyn ShapeObj, dyn ShapeObj + Send + Sync);
+
+#[test]
+fn clone()
+{
+    let shapes: Vec<Box<dyn ShapeObj>> = vec![Box::new(Square(1)), Box::new(Circle(2))];
+    let clones = shapes.clone();
+    assert!(shapes.iter().zip(clones.iter()).all(|(shape, clone)| **shape == **clone));
+
+    let shape = Box::new(Square(3)) as Box<dyn ShapeObj + Send + Sync>;
+    let clone = std::thread::spawn(move || shape.clone()).join().unwrap();
+    assert!((*clone).as_any().is::<Square>());
+}
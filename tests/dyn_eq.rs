@@ -0,0 +1,30 @@
+use spellcast::{impl_is, impl_object, DynHash, DynOrd, DynTotalEq};
+
+trait ShapeObj: DynTotalEq + DynHash + DynOrd {}
+
+impl_object!(ShapeObj with PartialEq, Eq, Hash, PartialOrd, Ord);
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Circle(u32);
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Square(u32);
+
+impl ShapeObj for Circle {}
+impl ShapeObj for Square {}
+
+impl_is!(Circle: dyn ShapeObj);
+impl_is!(Square: dyn ShapeObj);
+
+#[test]
+fn compare_and_hash()
+{
+    let shapes: Vec<Box<dyn ShapeObj>> = vec![Box::new(Square(1)), Box::new(Circle(2)), Box::new(Circle(1)), Box::new(Circle(1))];
+
+    assert!(*shapes[2] == *shapes[3]);
+    assert!(*shapes[1] != *shapes[2]);
+    assert!(*shapes[0] != *shapes[2]);
+    assert!(*shapes[2] < *shapes[1]);
+
+    let set: std::collections::HashSet<_> = shapes.iter().collect();
+    assert_eq!(set.len(), 3);
+}
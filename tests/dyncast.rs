@@ -0,0 +1,116 @@
+#![cfg(feature = "dyncast")]
+
+use spellcast::{dyncast::*, impl_dyncast, impl_is, impl_object, AsAny, DynImplements};
+
+trait EntityObj: DynImplements
+{
+    fn name(&self) -> &str;
+    fn rename(&mut self, name: &str);
+}
+trait PlayerObj: EntityObj
+{
+    fn is_human(&self) -> bool;
+}
+
+impl_dyncast!(PlayerObj: EntityObj, reverse);
+impl_object!(EntityObj);
+impl_is!(dyn PlayerObj: dyn PlayerObj, dyn EntityObj);
+impl_is!(Human: dyn PlayerObj);
+impl_object!(Human: dyn PlayerObj);
+impl_object!(Rock:);
+
+struct Human
+{
+    name: String
+}
+
+impl EntityObj for Human
+{
+    fn name(&self) -> &str
+    {
+        &self.name
+    }
+    fn rename(&mut self, name: &str)
+    {
+        self.name = name.to_string()
+    }
+}
+impl PlayerObj for Human
+{
+    fn is_human(&self) -> bool
+    {
+        true
+    }
+}
+
+struct Rock;
+
+impl EntityObj for Rock
+{
+    fn name(&self) -> &str
+    {
+        "rock"
+    }
+    fn rename(&mut self, _: &str) {}
+}
+
+fn player() -> Box<dyn PlayerObj>
+{
+    Box::new(Human {name: "Alice".to_string()})
+}
+
+#[test]
+fn dyncast_box()
+{
+    let player = player();
+    assert!(player.is_human());
+
+    let entity: Box<dyn EntityObj> = player.dyncast();
+    assert_eq!(entity.name(), "Alice");
+}
+
+#[test]
+fn dyncast_ref()
+{
+    let mut player = player();
+
+    let entity: &mut dyn EntityObj = (*player).dyncast_mut();
+    entity.rename("Bob");
+
+    let entity: &dyn EntityObj = (*player).dyncast_ref();
+    assert_eq!(entity.name(), "Bob");
+}
+
+#[test]
+fn try_dyncast()
+{
+    let player = player();
+
+    let entity: &dyn EntityObj = (*player).try_dyncast_ref().unwrap();
+    assert_eq!(entity.name(), "Alice");
+
+    let entity: Result<Box<dyn EntityObj>, Box<dyn PlayerObj>> = player.try_dyncast();
+    assert_eq!(entity.ok().unwrap().name(), "Alice");
+}
+
+#[test]
+fn try_dyncast_back()
+{
+    let entity = Box::new(Rock) as Box<dyn EntityObj>;
+
+    let player: Result<Box<dyn PlayerObj>, Box<dyn EntityObj>> = entity.try_dyncast();
+    let entity = player.err().unwrap();
+    assert_eq!(entity.name(), "rock");
+    assert!((*entity).as_any().is::<Rock>());
+
+    let mut entity: Box<dyn EntityObj> = player_entity();
+    assert!((*entity).try_dyncast_mut().map(|player: &mut dyn PlayerObj| player.is_human()).unwrap());
+
+    let player: Result<Box<dyn PlayerObj>, Box<dyn EntityObj>> = entity.try_dyncast();
+    assert_eq!(player.ok().unwrap().name(), "Alice");
+}
+
+fn player_entity() -> Box<dyn EntityObj>
+{
+    player().dyncast()
+}
@@ -0,0 +1,35 @@
+use spellcast::{impl_object, DynImplements, Implements};
+
+trait ControlObj: DynImplements {}
+trait PlayerObj
+{
+    fn is_human(&self) -> bool;
+}
+trait EntityObj {}
+
+impl_object!(ControlObj);
+
+#[derive(Debug)]
+struct Human;
+
+impl ControlObj for Human {}
+impl PlayerObj for Human
+{
+    fn is_human(&self) -> bool
+    {
+        true
+    }
+}
+
+impl_object!(Human: dyn PlayerObj, dyn core::fmt::Debug);
+
+#[test]
+fn implements()
+{
+    let mut control = Box::new(Human) as Box<dyn ControlObj>;
+
+    assert!(control.implements::<dyn PlayerObj>());
+    assert!(!control.implements::<dyn EntityObj>());
+    assert_eq!(format!("{:?}", control.as_trait::<dyn core::fmt::Debug>().unwrap()), "Human");
+    assert!(control.as_trait_mut::<dyn PlayerObj>().unwrap().is_human());
+}
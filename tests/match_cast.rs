@@ -0,0 +1,67 @@
+use core::any::Any;
+
+use spellcast::{impl_is, impl_object, match_cast};
+
+trait PlayerObj: Any
+{
+    fn name(&self) -> &str;
+}
+trait ControlObj: Any {}
+
+impl_object!(PlayerObj);
+
+#[derive(PartialEq, Debug)]
+struct Human;
+#[derive(PartialEq, Debug)]
+struct Bot;
+
+impl PlayerObj for Human
+{
+    fn name(&self) -> &str
+    {
+        "human"
+    }
+}
+impl ControlObj for Human {}
+impl PlayerObj for Bot
+{
+    fn name(&self) -> &str
+    {
+        "bot"
+    }
+}
+
+impl_is!(Human: dyn PlayerObj, dyn ControlObj);
+impl_is!(Bot: dyn PlayerObj);
+
+#[test]
+fn match_cast()
+{
+    let mut player = Box::new(Human) as Box<dyn PlayerObj>;
+
+    let name = match_cast!((&*player) {
+        bot: Bot => bot.name(),
+        dyn ControlObj via Human as _control => "controlled",
+        _ => "unknown"
+    });
+    assert_eq!(name, "controlled");
+
+    let is_human = match_cast!((&mut *player) {
+        human: Human => {
+            let _: &mut Human = human;
+            true
+        },
+        _ => false
+    });
+    assert!(is_human);
+
+    let player = match_cast!(player {
+        _bot: Bot => unreachable!(),
+        player => player
+    });
+    let human = match_cast!(player {
+        human: Human => human,
+        _ => unreachable!()
+    });
+    assert_eq!(*human, Human);
+}
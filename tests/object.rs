@@ -0,0 +1,140 @@
+#![cfg(feature = "derive")]
+
+use spellcast::{convert::*, downcast::*, impl_is, AsAny};
+
+#[spellcast::object]
+trait PlayerObj
+{
+    fn is_human(&self) -> bool;
+}
+
+#[spellcast::object]
+trait ContainerObj<'a, T, const N: usize>
+where
+    T: 'static
+{
+    type Item;
+
+    fn items(&self) -> [&'a T; N];
+}
+
+#[spellcast::object(PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+trait ShapeObj {}
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+struct Circle(u32);
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+struct Square(u32);
+
+impl ShapeObj for Circle {}
+impl ShapeObj for Square {}
+
+mod entity
+{
+    #[spellcast::object]
+    pub trait EntityObj {}
+}
+
+#[derive(PartialEq, Debug)]
+struct Human;
+
+impl PlayerObj for Human
+{
+    fn is_human(&self) -> bool
+    {
+        true
+    }
+}
+
+struct Chest;
+
+static GOLD: u32 = 100;
+
+impl ContainerObj<'static, u32, 2> for Chest
+{
+    type Item = u32;
+
+    fn items(&self) -> [&'static u32; 2]
+    {
+        [&GOLD, &GOLD]
+    }
+}
+
+impl entity::EntityObj for Human {}
+
+impl_is!(Human: dyn PlayerObj, dyn PlayerObj + Send + Sync, dyn entity::EntityObj);
+impl_is!(Circle: dyn ShapeObj);
+impl_is!(Square: dyn ShapeObj);
+impl_is!(Chest: dyn ContainerObj<'static, u32, 2, Item = u32>);
+
+#[test]
+fn downcast()
+{
+    let player = Box::new(Human) as Box<dyn PlayerObj>;
+    assert!(player.is_human());
+
+    assert_eq!(Human::downcast_from_ref(&*player), Some(&Human));
+    let player = <dyn PlayerObj>::downcast_from(player).ok().unwrap();
+    let human: Result<Box<Human>, Box<dyn PlayerObj>> = player.downcast();
+    assert_eq!(*human.ok().unwrap(), Human);
+
+    let player = Box::new(Human) as Box<dyn PlayerObj + Send + Sync>;
+    assert!((*player).as_any_send_sync().is::<Human>());
+    let human: Result<Box<Human>, Box<dyn PlayerObj + Send + Sync>> = player.downcast();
+    assert_eq!(*human.ok().unwrap(), Human);
+}
+
+#[test]
+fn convert_into_self()
+{
+    let player = Box::new(Human) as Box<dyn PlayerObj>;
+    let player: Result<Box<dyn PlayerObj>, Box<dyn PlayerObj>> = player.try_convert_into();
+    let player = player.ok().unwrap();
+    assert!(player.is_human());
+}
+
+#[test]
+fn generics_and_associated_types()
+{
+    let container = Box::new(Chest) as Box<dyn ContainerObj<'static, u32, 2, Item = u32>>;
+    assert_eq!(container.items(), [&100, &100]);
+
+    let container = <dyn ContainerObj<'static, u32, 2, Item = u32>>::downcast_from(container).ok().unwrap();
+    let chest: Result<Box<Chest>, Box<dyn ContainerObj<'static, u32, 2, Item = u32>>> = container.downcast();
+    assert!(chest.is_ok());
+}
+
+#[test]
+fn trait_paths()
+{
+    let entity = Box::new(Human) as Box<dyn entity::EntityObj>;
+    assert_eq!(Human::downcast_from_ref(&*entity), Some(&Human));
+}
+
+#[test]
+fn compare_and_hash()
+{
+    let shapes: Vec<Box<dyn ShapeObj>> = vec![Box::new(Square(1)), Box::new(Circle(2)), Box::new(Circle(1)), Box::new(Circle(1))];
+
+    assert!(*shapes[2] == *shapes[3]);
+    assert!(*shapes[1] != *shapes[2]);
+    assert!(*shapes[0] != *shapes[2]);
+    assert!(*shapes[2] < *shapes[1]);
+
+    let set: std::collections::HashSet<_> = shapes.iter().collect();
+    assert_eq!(set.len(), 3);
+
+    let set: std::collections::BTreeSet<_> = shapes.iter().collect();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn clone()
+{
+    let shape = Box::new(Circle(1)) as Box<dyn ShapeObj + Send>;
+    let clone = shape.clone();
+
+    let (shape, clone): (&dyn ShapeObj, &dyn ShapeObj) = (&*shape, &*clone);
+    assert!(clone == shape);
+    assert!(Circle::downcast_from_ref(clone).is_some());
+}
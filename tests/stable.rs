@@ -0,0 +1,98 @@
+//! The subset of the API that works without the `nightly` feature, for declared object traits.
+
+use core::any::Any;
+
+use spellcast::{downcast::*, upcast::*, veecast::*, impl_is, impl_object};
+
+trait EntityObj: Any
+{
+    fn name(&self) -> &str;
+}
+trait PlayerObj: EntityObj
+{
+    fn is_human(&self) -> bool;
+}
+trait ControlObj: Any {}
+
+impl_object!(EntityObj);
+impl_object!(PlayerObj);
+impl_object!(ControlObj);
+
+#[derive(PartialEq, Debug)]
+struct Human;
+#[derive(PartialEq, Debug)]
+struct Bot;
+
+impl EntityObj for Human
+{
+    fn name(&self) -> &str
+    {
+        "human"
+    }
+}
+impl PlayerObj for Human
+{
+    fn is_human(&self) -> bool
+    {
+        true
+    }
+}
+impl ControlObj for Human {}
+impl EntityObj for Bot
+{
+    fn name(&self) -> &str
+    {
+        "bot"
+    }
+}
+impl PlayerObj for Bot
+{
+    fn is_human(&self) -> bool
+    {
+        false
+    }
+}
+
+impl_is!(Human: dyn EntityObj, dyn PlayerObj, dyn ControlObj);
+impl_is!(Bot: dyn EntityObj, dyn PlayerObj);
+impl_is!(dyn PlayerObj: dyn EntityObj);
+
+#[test]
+fn upcast()
+{
+    let player = <dyn PlayerObj>::upcast_from(Box::new(Human));
+    assert!(player.is_human());
+
+    let entity = <dyn EntityObj>::upcast_from(player);
+    assert_eq!(entity.name(), "human");
+
+    let entity = <dyn EntityObj>::upcast_from_rc(std::rc::Rc::new(Bot));
+    assert_eq!(entity.name(), "bot");
+}
+
+#[test]
+fn downcast()
+{
+    let player = <dyn PlayerObj>::upcast_from(Box::new(Bot));
+
+    assert_eq!(Human::downcast_from_ref(&*player), None);
+    assert_eq!(Bot::downcast_from_ref(&*player), Some(&Bot));
+
+    let human: Result<Box<Human>, Box<dyn PlayerObj>> = player.downcast();
+    let player = human.err().unwrap();
+
+    let bot: Result<Box<Bot>, Box<dyn PlayerObj>> = player.downcast();
+    assert_eq!(*bot.ok().unwrap(), Bot);
+}
+
+#[test]
+fn veecast()
+{
+    let player = <dyn PlayerObj>::upcast_from(Box::new(Human));
+
+    let control: Option<&dyn ControlObj> = (*player).veecast_ref::<Human>();
+    assert!(control.is_some());
+
+    let control: Result<Box<dyn ControlObj>, Box<dyn PlayerObj>> = player.veecast::<Human>();
+    assert!(control.is_ok());
+}
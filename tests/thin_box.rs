@@ -0,0 +1,104 @@
+#![cfg(feature = "nightly")]
+
+use std::rc::Rc;
+
+use spellcast::ThinBox;
+
+trait EntityObj
+{
+    fn name(&self) -> &str;
+    fn rename(&mut self, name: &str);
+}
+trait PlayerObj: EntityObj {}
+trait ControlObj {}
+
+#[derive(PartialEq, Debug)]
+struct Human
+{
+    name: String
+}
+#[repr(align(64))]
+struct Bot
+{
+    _drops: Rc<()>
+}
+
+impl EntityObj for Human
+{
+    fn name(&self) -> &str
+    {
+        &self.name
+    }
+    fn rename(&mut self, name: &str)
+    {
+        self.name = name.to_string()
+    }
+}
+impl PlayerObj for Human {}
+impl ControlObj for Human {}
+impl EntityObj for Bot
+{
+    fn name(&self) -> &str
+    {
+        "bot"
+    }
+    fn rename(&mut self, _: &str) {}
+}
+impl PlayerObj for Bot {}
+
+#[test]
+fn upcast()
+{
+    let mut player = ThinBox::<dyn PlayerObj>::new(Human {name: "Alice".to_string()});
+    player.rename("Bob");
+
+    let entity: ThinBox<dyn EntityObj> = player.upcast();
+    assert_eq!(entity.name(), "Bob");
+}
+
+#[test]
+fn downcast()
+{
+    let mut player = ThinBox::<dyn PlayerObj>::new(Human {name: "Alice".to_string()});
+
+    assert!(player.downcast_ref::<Bot>().is_none());
+    player.downcast_mut::<Human>().unwrap().name.push('!');
+
+    let player = player.downcast::<Bot>().err().unwrap();
+    assert_eq!(player.downcast::<Human>().ok(), Some(Box::new(Human {name: "Alice!".to_string()})));
+}
+
+#[test]
+fn veecast()
+{
+    let mut entity = ThinBox::<dyn EntityObj>::new(Human {name: "Alice".to_string()});
+
+    assert!(entity.veecast_ref::<Bot, dyn PlayerObj>().is_none());
+    assert!(entity.veecast_mut::<Human, dyn ControlObj>().is_some());
+
+    let control: ThinBox<dyn ControlObj> = entity.veecast::<Human, _>().ok().unwrap();
+    assert!(control.is::<Human>());
+}
+
+#[test]
+fn drop()
+{
+    let drops = Rc::new(());
+
+    let player = ThinBox::<dyn PlayerObj>::new(Bot {_drops: drops.clone()});
+    let entity: ThinBox<dyn EntityObj> = player.upcast();
+    assert_eq!(entity.name(), "bot");
+    assert_eq!(Rc::strong_count(&drops), 2);
+
+    core::mem::drop(entity);
+    assert_eq!(Rc::strong_count(&drops), 1);
+}
+
+#[test]
+fn unpin()
+{
+    fn assert_unpin<T: Unpin>(_: &T) {}
+
+    let player = ThinBox::<dyn PlayerObj>::new(Human {name: "Alice".to_string()});
+    assert_unpin(&player);
+}
@@ -0,0 +1,58 @@
+use core::any::Any;
+
+use spellcast::{impl_is, impl_object, TraitMap};
+
+trait EntityObj: Any {}
+trait PlayerObj
+{
+    fn is_human(&self) -> bool;
+}
+trait ControlObj {}
+
+impl_object!(EntityObj);
+
+#[derive(PartialEq, Debug)]
+struct Human;
+#[derive(PartialEq, Debug)]
+struct Bot;
+
+impl EntityObj for Human {}
+impl PlayerObj for Human
+{
+    fn is_human(&self) -> bool
+    {
+        true
+    }
+}
+impl ControlObj for Human {}
+impl EntityObj for Bot {}
+impl PlayerObj for Bot
+{
+    fn is_human(&self) -> bool
+    {
+        false
+    }
+}
+
+impl_is!(Human: dyn EntityObj, dyn PlayerObj, dyn ControlObj);
+impl_is!(Bot: dyn EntityObj, dyn PlayerObj);
+
+#[test]
+fn trait_map()
+{
+    let mut entities = TraitMap::<dyn EntityObj>::new();
+    entities.insert(Human)
+        .implements::<dyn PlayerObj>()
+        .implements::<dyn ControlObj>();
+    entities.insert(Bot)
+        .implements::<dyn PlayerObj>();
+    assert_eq!(entities.len(), 2);
+
+    assert_eq!(entities.get::<Human>(), Some(&Human));
+    assert_eq!(entities.iter_as::<dyn PlayerObj>().filter(|player| player.is_human()).count(), 1);
+    assert_eq!(entities.iter_as::<dyn ControlObj>().count(), 1);
+
+    assert_eq!(entities.remove::<Human>(), Some(Box::new(Human)));
+    assert!(!entities.contains::<Human>());
+    assert_eq!(entities.iter_as_mut::<dyn ControlObj>().count(), 0);
+}
@@ -0,0 +1,64 @@
+use core::any::Any;
+
+use spellcast::{veecast::*, impl_is, impl_object};
+
+trait PlayerObj: Any {}
+trait EntityObj
+{
+    fn name(&self) -> &str;
+}
+trait ControlObj {}
+
+impl_object!(PlayerObj);
+
+struct Human;
+struct Bot;
+
+impl PlayerObj for Human {}
+impl EntityObj for Human
+{
+    fn name(&self) -> &str
+    {
+        "human"
+    }
+}
+impl ControlObj for Human {}
+impl PlayerObj for Bot {}
+impl EntityObj for Bot
+{
+    fn name(&self) -> &str
+    {
+        "bot"
+    }
+}
+
+impl_is!(Human: dyn PlayerObj, dyn EntityObj, dyn ControlObj);
+impl_is!(Bot: dyn PlayerObj, dyn EntityObj);
+
+#[test]
+fn veecast_any()
+{
+    let player = Box::new(Human) as Box<dyn PlayerObj>;
+
+    assert!((*player).veecast_any_ref::<(Human,), dyn ControlObj>().is_some());
+
+    let player = Box::new(Bot) as Box<dyn PlayerObj>;
+    let player = player.veecast_any::<(Human,), dyn EntityObj>().err().unwrap();
+
+    let entity = player.veecast_any::<(Human, Bot), dyn EntityObj>().ok().unwrap();
+    assert_eq!(entity.name(), "bot");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn veecaster()
+{
+    let veecaster = Veecaster::<dyn EntityObj>::new()
+        .with::<Human>();
+
+    let player = Box::new(Bot) as Box<dyn PlayerObj>;
+    assert!(veecaster.cast_ref(&*player).is_none());
+
+    let veecaster = veecaster.with::<Bot>();
+    assert_eq!(veecaster.cast_ref(&*player).unwrap().name(), "bot");
+}